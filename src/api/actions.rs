@@ -0,0 +1,397 @@
+use crate::client::{ManagementClient, PollConfig};
+use crate::error::{Auth0Error, Result};
+use crate::types::ActionId;
+use crate::types::PaginationParams;
+use crate::types::actions::{
+    Action, ActionBinding, ActionBindingUpdate, ActionBindingsPage, ActionBuildStatus,
+    ActionTestResult, ActionTrigger, ActionTriggerId, ActionVersion, ActionVersionsPage,
+    ActionsPage, CreateActionRequest, ListActionsParams, UpdateActionRequest,
+    UpdateBindingsRequest,
+};
+
+/// API operations for Auth0 Actions.
+///
+/// Covers the action lifecycle: edit a draft, wait for it to build, deploy it as a new
+/// version, and bind it to a trigger so it runs.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{
+///     ActionBindingUpdate, ActionTrigger, ActionTriggerId, CreateActionRequest, PollConfig,
+/// };
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let mut request = CreateActionRequest::new(
+///     "add-roles",
+///     ActionTrigger::new(ActionTriggerId::PostLogin, "v3"),
+/// );
+/// request.code = Some("exports.onExecutePostLogin = async (event, api) => {};".into());
+///
+/// let action = client.actions().create(request).await?;
+/// client.actions().wait_until_built(action.id.clone(), &PollConfig::default()).await?;
+/// client.actions().deploy(action.id.clone()).await?;
+/// client
+///     .actions()
+///     .update_bindings(
+///         ActionTriggerId::PostLogin,
+///         vec![ActionBindingUpdate::action(action.id.clone())],
+///     )
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Actions API documentation](https://auth0.com/docs/api/management/v2/actions/get-actions)
+/// for detailed information on actions.
+pub struct ActionsApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> ActionsApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List actions with optional filtering and pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional filters such as trigger, name and deployment state.
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of actions.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/get-actions>
+    pub async fn list(&self, params: Option<ListActionsParams>) -> Result<ActionsPage> {
+        let mut url = self.client.base_url().join("api/v2/actions/actions")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// Get an action by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the action, including its draft and deployed version.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/get-action>
+    pub async fn get(&self, id: ActionId) -> Result<Action> {
+        let url = self.action_url(&id, "")?;
+        self.client.get(url).await
+    }
+
+    /// Create an action. The action is not deployed until [`deploy`](Self::deploy) is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The action's name, triggers, code, dependencies and secrets.
+    ///
+    /// # Returns
+    ///
+    /// Returns the created action.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/post-action>
+    pub async fn create(&self, request: CreateActionRequest) -> Result<Action> {
+        let url = self.client.base_url().join("api/v2/actions/actions")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Update an action's draft.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action's unique identifier.
+    /// * `request` - Fields to update. Only provided fields are modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated action.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/patch-action>
+    pub async fn update(&self, id: ActionId, request: UpdateActionRequest) -> Result<Action> {
+        let url = self.action_url(&id, "")?;
+        self.client.patch(url, &request).await
+    }
+
+    /// Delete an action.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action's unique identifier.
+    /// * `force` - Also remove the action's trigger bindings. Without it, deleting a bound
+    ///   action fails.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/delete-action>
+    pub async fn delete(&self, id: ActionId, force: bool) -> Result<()> {
+        let mut url = self.action_url(&id, "")?;
+        if force {
+            url.query_pairs_mut().append_pair("force", "true");
+        }
+        self.client.delete(url).await
+    }
+
+    /// Deploy the action's current draft as a new version.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly deployed version.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/post-deploy-action>
+    pub async fn deploy(&self, id: ActionId) -> Result<ActionVersion> {
+        let url = self.action_url(&id, "/deploy")?;
+        self.client.post(url, &serde_json::json!({})).await
+    }
+
+    /// List the versions of an action.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action's unique identifier.
+    /// * `params` - Optional pagination parameters.
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of versions, newest first.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/get-action-versions>
+    pub async fn list_versions(
+        &self,
+        id: ActionId,
+        params: Option<PaginationParams>,
+    ) -> Result<ActionVersionsPage> {
+        let mut url = self.action_url(&id, "/versions")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// Get a specific version of an action.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action's unique identifier.
+    /// * `version_id` - The version's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/get-action-version>
+    pub async fn get_version(&self, id: ActionId, version_id: &str) -> Result<ActionVersion> {
+        let url = self.action_url(
+            &id,
+            &format!("/versions/{}", urlencoding::encode(version_id)),
+        )?;
+        self.client.get(url).await
+    }
+
+    /// Roll back to a previous version by deploying it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action's unique identifier.
+    /// * `version_id` - The version to redeploy.
+    /// * `update_draft` - Also replace the current draft with this version's code.
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly deployed version.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/post-deploy-draft-version>
+    pub async fn rollback(
+        &self,
+        id: ActionId,
+        version_id: &str,
+        update_draft: bool,
+    ) -> Result<ActionVersion> {
+        let url = self.action_url(
+            &id,
+            &format!("/versions/{}/deploy", urlencoding::encode(version_id)),
+        )?;
+        self.client
+            .post(url, &serde_json::json!({ "update_draft": update_draft }))
+            .await
+    }
+
+    /// Run an action's draft against a test payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action's unique identifier.
+    /// * `payload` - The trigger event to run the action with.
+    ///
+    /// # Returns
+    ///
+    /// Returns the execution result.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/post-test-action>
+    pub async fn test(&self, id: ActionId, payload: serde_json::Value) -> Result<ActionTestResult> {
+        let url = self.action_url(&id, "/test")?;
+        self.client
+            .post(url, &serde_json::json!({ "payload": payload }))
+            .await
+    }
+
+    /// Wait until an action's draft has finished building.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The action's unique identifier.
+    /// * `poll` - Polling interval, backoff and deadline.
+    ///
+    /// # Returns
+    ///
+    /// Returns the action once its status is `built`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::OperationFailed` if the build fails, or `Auth0Error::Timeout` if
+    /// it has not finished before the deadline.
+    pub async fn wait_until_built(&self, id: ActionId, poll: &PollConfig) -> Result<Action> {
+        poll.poll_until(
+            &format!("action {} did not finish building", id),
+            || async {
+                let action = self.get(id.clone()).await?;
+                match action.status {
+                    Some(ActionBuildStatus::Built) => Ok(Some(action)),
+                    Some(ActionBuildStatus::Failed) => Err(Auth0Error::OperationFailed(format!(
+                        "action {} failed to build",
+                        id
+                    ))),
+                    _ => Ok(None),
+                }
+            },
+        )
+        .await
+    }
+
+    /// List the available triggers.
+    ///
+    /// # Returns
+    ///
+    /// Returns every trigger and version actions can be bound to.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/get-triggers>
+    pub async fn list_triggers(&self) -> Result<Vec<ActionTrigger>> {
+        #[derive(serde::Deserialize)]
+        struct Triggers {
+            triggers: Vec<ActionTrigger>,
+        }
+
+        let url = self.client.base_url().join("api/v2/actions/triggers")?;
+        let response: Triggers = self.client.get(url).await?;
+        Ok(response.triggers)
+    }
+
+    /// List the actions bound to a trigger, in execution order.
+    ///
+    /// # Arguments
+    ///
+    /// * `trigger` - The trigger to inspect.
+    /// * `params` - Optional pagination parameters.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/get-bindings>
+    pub async fn list_bindings(
+        &self,
+        trigger: ActionTriggerId,
+        params: Option<PaginationParams>,
+    ) -> Result<ActionBindingsPage> {
+        let mut url = self.bindings_url(&trigger)?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// Replace the actions bound to a trigger.
+    ///
+    /// # Arguments
+    ///
+    /// * `trigger` - The trigger to update.
+    /// * `bindings` - The complete, ordered list of bindings. Omitted actions are unbound.
+    ///
+    /// # Returns
+    ///
+    /// Returns the trigger's bindings after the update.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/actions/patch-bindings>
+    pub async fn update_bindings(
+        &self,
+        trigger: ActionTriggerId,
+        bindings: Vec<ActionBindingUpdate>,
+    ) -> Result<Vec<ActionBinding>> {
+        #[derive(serde::Deserialize)]
+        struct Bindings {
+            bindings: Vec<ActionBinding>,
+        }
+
+        let url = self.bindings_url(&trigger)?;
+        let response: Bindings = self
+            .client
+            .patch(url, &UpdateBindingsRequest { bindings })
+            .await?;
+        Ok(response.bindings)
+    }
+
+    fn action_url(&self, id: &ActionId, suffix: &str) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/actions/actions/{}{}",
+            urlencoding::encode(id.as_str()),
+            suffix
+        ))?)
+    }
+
+    fn bindings_url(&self, trigger: &ActionTriggerId) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/actions/triggers/{}/bindings",
+            urlencoding::encode(trigger.as_str())
+        ))?)
+    }
+}
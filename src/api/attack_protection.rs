@@ -0,0 +1,140 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::attack_protection::{
+    BreachedPasswordDetection, BruteForceProtection, SuspiciousIpThrottling,
+};
+
+/// API operations for Auth0 Attack Protection.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{BruteForceMode, BruteForceProtection, Shield};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let settings = BruteForceProtection {
+///     enabled: Some(true),
+///     shields: Some(vec![Shield::Block, Shield::UserNotification]),
+///     mode: Some(BruteForceMode::CountPerIdentifierAndIp),
+///     max_attempts: Some(5),
+///     ..Default::default()
+/// };
+/// client
+///     .attack_protection()
+///     .update_brute_force_protection(settings)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Attack Protection API documentation](https://auth0.com/docs/api/management/v2/attack-protection/get-brute-force-protection)
+/// for detailed information on attack protection.
+pub struct AttackProtectionApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> AttackProtectionApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the brute-force protection settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/attack-protection/get-brute-force-protection>
+    pub async fn get_brute_force_protection(&self) -> Result<BruteForceProtection> {
+        let url = self.settings_url("brute-force-protection")?;
+        self.client.get(url).await
+    }
+
+    /// Update the brute-force protection settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Settings to update. Fields left as `None` are not modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/attack-protection/patch-brute-force-protection>
+    pub async fn update_brute_force_protection(
+        &self,
+        settings: BruteForceProtection,
+    ) -> Result<BruteForceProtection> {
+        let url = self.settings_url("brute-force-protection")?;
+        self.client.patch(url, &settings).await
+    }
+
+    /// Get the breached password detection settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/attack-protection/get-breached-password-detection>
+    pub async fn get_breached_password_detection(&self) -> Result<BreachedPasswordDetection> {
+        let url = self.settings_url("breached-password-detection")?;
+        self.client.get(url).await
+    }
+
+    /// Update the breached password detection settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Settings to update. Fields left as `None` are not modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/attack-protection/patch-breached-password-detection>
+    pub async fn update_breached_password_detection(
+        &self,
+        settings: BreachedPasswordDetection,
+    ) -> Result<BreachedPasswordDetection> {
+        let url = self.settings_url("breached-password-detection")?;
+        self.client.patch(url, &settings).await
+    }
+
+    /// Get the suspicious IP throttling settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/attack-protection/get-suspicious-ip-throttling>
+    pub async fn get_suspicious_ip_throttling(&self) -> Result<SuspiciousIpThrottling> {
+        let url = self.settings_url("suspicious-ip-throttling")?;
+        self.client.get(url).await
+    }
+
+    /// Update the suspicious IP throttling settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Settings to update. Fields left as `None` are not modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/attack-protection/patch-suspicious-ip-throttling>
+    pub async fn update_suspicious_ip_throttling(
+        &self,
+        settings: SuspiciousIpThrottling,
+    ) -> Result<SuspiciousIpThrottling> {
+        let url = self.settings_url("suspicious-ip-throttling")?;
+        self.client.patch(url, &settings).await
+    }
+
+    fn settings_url(&self, feature: &str) -> Result<url::Url> {
+        Ok(self
+            .client
+            .base_url()
+            .join(&format!("api/v2/attack-protection/{}", feature))?)
+    }
+}
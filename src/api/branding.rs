@@ -0,0 +1,208 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::branding::{
+    Branding, BrandingTheme, BrandingThemeRequest, UniversalLoginTemplate, UpdateBrandingRequest,
+};
+
+/// API operations for Auth0 Branding.
+///
+/// Covers the tenant's logo, colors, font and favicon, Universal Login themes, and the
+/// custom Universal Login page template.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{BrandingColors, Patch, UpdateBrandingRequest};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let request = UpdateBrandingRequest {
+///     logo_url: Patch::Value("https://cdn.example.com/logo.png".into()),
+///     colors: Patch::Value(BrandingColors {
+///         primary: Some("#ff5500".into()),
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// client.branding().update(request).await?;
+///
+/// // Tweak the default theme
+/// let mut theme = client.branding().get_default_theme().await?;
+/// theme.settings.colors.insert("primary_button".into(), "#ff5500".into());
+/// let id = theme.theme_id.clone();
+/// client.branding().update_theme(&id, theme.into()).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Branding API documentation](https://auth0.com/docs/api/management/v2/branding/get-branding)
+/// for detailed information on branding.
+pub struct BrandingApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> BrandingApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the tenant's branding settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/get-branding>
+    pub async fn get(&self) -> Result<Branding> {
+        let url = self.client.base_url().join("api/v2/branding")?;
+        self.client.get(url).await
+    }
+
+    /// Update the tenant's branding settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Settings to update. Only provided fields are modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/patch-branding>
+    pub async fn update(&self, request: UpdateBrandingRequest) -> Result<Branding> {
+        let url = self.client.base_url().join("api/v2/branding")?;
+        self.client.patch(url, &request).await
+    }
+
+    /// Get the default Universal Login theme.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/get-default-branding-theme>
+    pub async fn get_default_theme(&self) -> Result<BrandingTheme> {
+        let url = self
+            .client
+            .base_url()
+            .join("api/v2/branding/themes/default")?;
+        self.client.get(url).await
+    }
+
+    /// Get a Universal Login theme.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme_id` - The theme's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/get-branding-theme>
+    pub async fn get_theme(&self, theme_id: &str) -> Result<BrandingTheme> {
+        let url = self.theme_url(theme_id)?;
+        self.client.get(url).await
+    }
+
+    /// Create a Universal Login theme. A tenant can have only one theme.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The theme's display name and every style section.
+    ///
+    /// # Returns
+    ///
+    /// Returns the created theme.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/post-branding-theme>
+    pub async fn create_theme(&self, request: BrandingThemeRequest) -> Result<BrandingTheme> {
+        let url = self.client.base_url().join("api/v2/branding/themes")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Update a Universal Login theme.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme_id` - The theme's unique identifier.
+    /// * `request` - The full theme. Auth0 replaces every style section.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated theme.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/patch-branding-theme>
+    pub async fn update_theme(
+        &self,
+        theme_id: &str,
+        request: BrandingThemeRequest,
+    ) -> Result<BrandingTheme> {
+        let url = self.theme_url(theme_id)?;
+        self.client.patch(url, &request).await
+    }
+
+    /// Delete a Universal Login theme.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme_id` - The theme's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/delete-branding-theme>
+    pub async fn delete_theme(&self, theme_id: &str) -> Result<()> {
+        let url = self.theme_url(theme_id)?;
+        self.client.delete(url).await
+    }
+
+    /// Get the custom Universal Login page template.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/get-universal-login>
+    pub async fn get_universal_login_template(&self) -> Result<UniversalLoginTemplate> {
+        let url = self.universal_login_url()?;
+        self.client.get(url).await
+    }
+
+    /// Set the custom Universal Login page template. Requires a custom domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - Liquid template HTML containing `{%- auth0:head -%}` and
+    ///   `{%- auth0:widget -%}`.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/put-universal-login>
+    pub async fn set_universal_login_template(&self, template: impl Into<String>) -> Result<()> {
+        let url = self.universal_login_url()?;
+        self.client
+            .put_no_content(url, &serde_json::json!({ "template": template.into() }))
+            .await
+    }
+
+    /// Delete the custom Universal Login page template, restoring the default page.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/branding/delete-universal-login>
+    pub async fn delete_universal_login_template(&self) -> Result<()> {
+        let url = self.universal_login_url()?;
+        self.client.delete(url).await
+    }
+
+    fn theme_url(&self, theme_id: &str) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/branding/themes/{}",
+            urlencoding::encode(theme_id)
+        ))?)
+    }
+
+    fn universal_login_url(&self) -> Result<url::Url> {
+        Ok(self
+            .client
+            .base_url()
+            .join("api/v2/branding/templates/universal-login")?)
+    }
+}
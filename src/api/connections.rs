@@ -1,10 +1,15 @@
 use crate::client::ManagementClient;
 use crate::error::{Auth0Error, Result};
-use crate::types::ConnectionId;
 use crate::types::connections::{
-    Connection, ConnectionsPage, CreateConnectionRequest, ListConnectionsParams,
+    Connection, ConnectionsPage, CreateConnectionRequest, CreateScimTokenRequest,
+    EnabledClientUpdate, EnabledClientsPage, ListConnectionsParams, ScimConfiguration,
+    ScimConfigurationRequest, ScimDefaultMapping, ScimToken, ScimTokenCreated,
     UpdateConnectionRequest,
 };
+use crate::types::{CheckpointParams, ClientId, ConnectionId, Patch};
+
+/// Number of read-modify-write attempts made when the per-client endpoint is unavailable.
+const ENABLED_CLIENTS_MAX_ATTEMPTS: u32 = 3;
 
 /// API operations for Auth0 Connections.
 ///
@@ -208,4 +213,432 @@ impl<'a> ConnectionsApi<'a> {
 
         self.client.delete(url).await
     }
+
+    /// Check the status of an AD/LDAP connector.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns success if the connection is online. An offline connection is reported as
+    /// an `Auth0Error::Api` with status 404.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/get-status>
+    pub async fn status(&self, id: ConnectionId) -> Result<()> {
+        let url = self.connection_url(&id, "/status")?;
+        self.client.get_no_content(url).await
+    }
+
+    /// Delete a user from a database connection by email address.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    /// * `email` - The email address of the user to delete.
+    ///
+    /// # Returns
+    ///
+    /// Returns success or error.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::ConnectionId;
+    /// client
+    ///     .connections()
+    ///     .delete_user_by_email(ConnectionId::new("con_1234567890"), "user@example.com")
+    ///     .await?;
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/delete-users-by-email>
+    pub async fn delete_user_by_email(&self, id: ConnectionId, email: &str) -> Result<()> {
+        let mut url = self.connection_url(&id, "/users")?;
+        url.query_pairs_mut().append_pair("email", email);
+        self.client.delete(url).await
+    }
+
+    /// List the clients a connection is enabled for.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    /// * `params` - Optional checkpoint pagination parameters.
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of enabled clients and the checkpoint for the next page.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/get-connection-clients>
+    pub async fn list_enabled_clients(
+        &self,
+        id: ConnectionId,
+        params: Option<CheckpointParams>,
+    ) -> Result<EnabledClientsPage> {
+        let mut url = self.connection_url(&id, "/clients")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// Enable or disable a connection for one or more clients.
+    ///
+    /// Only the listed clients are changed; other clients keep their current state.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    /// * `updates` - Per-client enable/disable changes.
+    ///
+    /// # Returns
+    ///
+    /// Returns success or error.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::{ClientId, ConnectionId, EnabledClientUpdate};
+    /// client
+    ///     .connections()
+    ///     .update_enabled_clients(
+    ///         ConnectionId::new("con_1234567890"),
+    ///         vec![EnabledClientUpdate::enable(ClientId::new("client_123"))],
+    ///     )
+    ///     .await?;
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/patch-clients>
+    pub async fn update_enabled_clients(
+        &self,
+        id: ConnectionId,
+        updates: Vec<EnabledClientUpdate>,
+    ) -> Result<()> {
+        let url = self.connection_url(&id, "/clients")?;
+        self.client.patch_no_content(url, &updates).await
+    }
+
+    /// Enable a connection for a single client.
+    ///
+    /// Uses the per-client `PATCH /connections/{id}/clients` endpoint, which only touches
+    /// the given client. On tenants where that endpoint is unavailable, falls back to
+    /// reading `enabled_clients` and writing the updated list. The full list is read again
+    /// right before the write and the write is skipped if any entry changed; the written
+    /// list is then read back and compared. Either mismatch restarts from the fresh list.
+    ///
+    /// The Management API has no conditional writes, so a change landing between the
+    /// final check and the write can still be lost; the checks only narrow that window.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    /// * `client_id` - The client to enable the connection for.
+    ///
+    /// # Returns
+    ///
+    /// Returns success once the client is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::ConcurrentModification` if `enabled_clients` kept changing
+    /// under the fallback for several attempts.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::{ClientId, ConnectionId};
+    /// client
+    ///     .connections()
+    ///     .enable_for_client(ConnectionId::new("con_1234567890"), ClientId::new("client_123"))
+    ///     .await?;
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/patch-clients>
+    pub async fn enable_for_client(&self, id: ConnectionId, client_id: ClientId) -> Result<()> {
+        self.set_enabled_for_client(id, client_id, true).await
+    }
+
+    /// Disable a connection for a single client.
+    ///
+    /// Behaves like [`enable_for_client`](Self::enable_for_client), including the
+    /// read-modify-write fallback.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    /// * `client_id` - The client to disable the connection for.
+    ///
+    /// # Returns
+    ///
+    /// Returns success once the client is disabled.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/patch-clients>
+    pub async fn disable_for_client(&self, id: ConnectionId, client_id: ClientId) -> Result<()> {
+        self.set_enabled_for_client(id, client_id, false).await
+    }
+
+    async fn set_enabled_for_client(
+        &self,
+        id: ConnectionId,
+        client_id: ClientId,
+        enabled: bool,
+    ) -> Result<()> {
+        let update = EnabledClientUpdate {
+            client_id: client_id.clone(),
+            status: enabled,
+        };
+        match self.update_enabled_clients(id.clone(), vec![update]).await {
+            Err(Auth0Error::Api {
+                status: 404 | 405 | 501,
+                ..
+            }) => {}
+            other => return other,
+        }
+
+        let mut snapshot = self.enabled_clients_of(&id).await?;
+        for _ in 0..ENABLED_CLIENTS_MAX_ATTEMPTS {
+            if snapshot.iter().any(|c| c == client_id.as_str()) == enabled {
+                return Ok(());
+            }
+
+            let mut target = snapshot.clone();
+            if enabled {
+                target.push(client_id.to_string());
+            } else {
+                target.retain(|c| c != client_id.as_str());
+            }
+
+            // Only write if no other client's entry changed since the snapshot was taken.
+            let latest = self.enabled_clients_of(&id).await?;
+            if !same_clients(&latest, &snapshot) {
+                snapshot = latest;
+                continue;
+            }
+
+            let request = UpdateConnectionRequest {
+                enabled_clients: Patch::Value(target.clone()),
+                ..Default::default()
+            };
+            self.update(id.clone(), request).await?;
+
+            let written = self.enabled_clients_of(&id).await?;
+            if same_clients(&written, &target) {
+                return Ok(());
+            }
+            snapshot = written;
+        }
+
+        Err(Auth0Error::ConcurrentModification(format!(
+            "enabled_clients of connection {} changed concurrently; could not {} client {}",
+            id,
+            if enabled { "enable" } else { "disable" },
+            client_id
+        )))
+    }
+
+    /// Get the SCIM configuration of a connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the SCIM configuration if one exists.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/get-scim-configuration>
+    pub async fn get_scim_configuration(&self, id: ConnectionId) -> Result<ScimConfiguration> {
+        let url = self.connection_url(&id, "/scim-configuration")?;
+        self.client.get(url).await
+    }
+
+    /// Create a SCIM configuration for a connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    /// * `request` - Optional user ID attribute and attribute mapping. Defaults are used
+    ///   for omitted fields.
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly created SCIM configuration.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/post-scim-configuration>
+    pub async fn create_scim_configuration(
+        &self,
+        id: ConnectionId,
+        request: ScimConfigurationRequest,
+    ) -> Result<ScimConfiguration> {
+        let url = self.connection_url(&id, "/scim-configuration")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Update the SCIM configuration of a connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    /// * `request` - The user ID attribute and attribute mapping to store.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated SCIM configuration.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/patch-scim-configuration>
+    pub async fn update_scim_configuration(
+        &self,
+        id: ConnectionId,
+        request: ScimConfigurationRequest,
+    ) -> Result<ScimConfiguration> {
+        let url = self.connection_url(&id, "/scim-configuration")?;
+        self.client.patch(url, &request).await
+    }
+
+    /// Delete the SCIM configuration of a connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns success or error.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/delete-scim-configuration>
+    pub async fn delete_scim_configuration(&self, id: ConnectionId) -> Result<()> {
+        let url = self.connection_url(&id, "/scim-configuration")?;
+        self.client.delete(url).await
+    }
+
+    /// Get the default SCIM attribute mapping for a connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the default mapping for the connection's strategy.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/get-default-mapping>
+    pub async fn get_scim_default_mapping(&self, id: ConnectionId) -> Result<ScimDefaultMapping> {
+        let url = self.connection_url(&id, "/scim-configuration/default-mapping")?;
+        self.client.get(url).await
+    }
+
+    /// List the SCIM bearer tokens of a connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the tokens' metadata. Token values are never returned after creation.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/get-scim-tokens>
+    pub async fn list_scim_tokens(&self, id: ConnectionId) -> Result<Vec<ScimToken>> {
+        let url = self.connection_url(&id, "/scim-configuration/tokens")?;
+        self.client.get(url).await
+    }
+
+    /// Create a SCIM bearer token for a connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    /// * `request` - Token scopes and lifetime.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new token, including its value.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/post-scim-token>
+    pub async fn create_scim_token(
+        &self,
+        id: ConnectionId,
+        request: CreateScimTokenRequest,
+    ) -> Result<ScimTokenCreated> {
+        let url = self.connection_url(&id, "/scim-configuration/tokens")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Delete a SCIM bearer token.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The connection's unique identifier.
+    /// * `token_id` - The token's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns success or error.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/connections/delete-tokens-by-token-id>
+    pub async fn delete_scim_token(&self, id: ConnectionId, token_id: &str) -> Result<()> {
+        let url = self.connection_url(
+            &id,
+            &format!(
+                "/scim-configuration/tokens/{}",
+                urlencoding::encode(token_id)
+            ),
+        )?;
+        self.client.delete(url).await
+    }
+
+    async fn enabled_clients_of(&self, id: &ConnectionId) -> Result<Vec<String>> {
+        let connection = self.get(id.clone()).await?;
+        Ok(connection.enabled_clients.unwrap_or_default())
+    }
+
+    fn connection_url(&self, id: &ConnectionId, suffix: &str) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/connections/{}{}",
+            urlencoding::encode(id.as_str()),
+            suffix
+        ))?)
+    }
+}
+
+/// Whether two `enabled_clients` lists hold the same clients, ignoring order.
+fn same_clients(a: &[String], b: &[String]) -> bool {
+    let mut a: Vec<&str> = a.iter().map(String::as_str).collect();
+    let mut b: Vec<&str> = b.iter().map(String::as_str).collect();
+    a.sort_unstable();
+    b.sort_unstable();
+    a == b
 }
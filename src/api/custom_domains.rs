@@ -0,0 +1,189 @@
+use crate::client::{ManagementClient, PollConfig};
+use crate::error::{Auth0Error, Result};
+use crate::types::custom_domains::{
+    CreateCustomDomainRequest, CustomDomain, CustomDomainStatus, UpdateCustomDomainRequest,
+};
+
+/// API operations for Auth0 Custom Domains.
+///
+/// A custom domain is created, its DNS verification record is published, and it is then
+/// verified before it serves traffic.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{CreateCustomDomainRequest, CustomDomainType, PollConfig};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let domain = client
+///     .custom_domains()
+///     .create(CreateCustomDomainRequest::new(
+///         "login.example.com",
+///         CustomDomainType::Auth0ManagedCerts,
+///     ))
+///     .await?;
+///
+/// for method in domain.verification_methods() {
+///     println!("create {:?} record: {}", method.name, method.record);
+/// }
+///
+/// client
+///     .custom_domains()
+///     .wait_until_verified(&domain.custom_domain_id, &PollConfig::default())
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Custom Domains API documentation](https://auth0.com/docs/api/management/v2/custom-domains/get-custom-domains)
+/// for detailed information on custom domains.
+pub struct CustomDomainsApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> CustomDomainsApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List all custom domains.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/custom-domains/get-custom-domains>
+    pub async fn list(&self) -> Result<Vec<CustomDomain>> {
+        let url = self.client.base_url().join("api/v2/custom-domains")?;
+        self.client.get(url).await
+    }
+
+    /// Get a custom domain by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The custom domain's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/custom-domains/get-custom-domains-by-id>
+    pub async fn get(&self, id: &str) -> Result<CustomDomain> {
+        let url = self.domain_url(id, "")?;
+        self.client.get(url).await
+    }
+
+    /// Create a custom domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The domain name and certificate type.
+    ///
+    /// # Returns
+    ///
+    /// Returns the created domain, including the DNS records needed to verify it.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/custom-domains/post-custom-domains>
+    pub async fn create(&self, request: CreateCustomDomainRequest) -> Result<CustomDomain> {
+        let url = self.client.base_url().join("api/v2/custom-domains")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Update a custom domain's TLS policy or client IP header.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The custom domain's unique identifier.
+    /// * `request` - Fields to update. Only provided fields are modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated domain.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/custom-domains/patch-custom-domains-by-id>
+    pub async fn update(
+        &self,
+        id: &str,
+        request: UpdateCustomDomainRequest,
+    ) -> Result<CustomDomain> {
+        let url = self.domain_url(id, "")?;
+        self.client.patch(url, &request).await
+    }
+
+    /// Ask Auth0 to check the domain's DNS verification record.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The custom domain's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the domain with its updated status. A status of `ready` means the domain
+    /// has been verified.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/custom-domains/post-verify>
+    pub async fn verify(&self, id: &str) -> Result<CustomDomain> {
+        let url = self.domain_url(id, "/verify")?;
+        self.client.post(url, &serde_json::json!({})).await
+    }
+
+    /// Delete a custom domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The custom domain's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/custom-domains/delete-custom-domains-by-id>
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        let url = self.domain_url(id, "")?;
+        self.client.delete(url).await
+    }
+
+    /// Call [`verify`](Self::verify) until the domain is ready.
+    ///
+    /// DNS changes can take a while to propagate, so use a generous `timeout`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The custom domain's unique identifier.
+    /// * `poll` - Polling interval, backoff and deadline.
+    ///
+    /// # Returns
+    ///
+    /// Returns the domain once its status is `ready`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::OperationFailed` if the domain is disabled, or
+    /// `Auth0Error::Timeout` if it is not verified before the deadline.
+    pub async fn wait_until_verified(&self, id: &str, poll: &PollConfig) -> Result<CustomDomain> {
+        poll.poll_until(
+            &format!("custom domain {} was not verified", id),
+            || async {
+                let domain = self.verify(id).await?;
+                match domain.status {
+                    CustomDomainStatus::Ready => Ok(Some(domain)),
+                    CustomDomainStatus::Disabled => Err(Auth0Error::OperationFailed(format!(
+                        "custom domain {} is disabled",
+                        id
+                    ))),
+                    _ => Ok(None),
+                }
+            },
+        )
+        .await
+    }
+
+    fn domain_url(&self, id: &str, suffix: &str) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/custom-domains/{}{}",
+            urlencoding::encode(id),
+            suffix
+        ))?)
+    }
+}
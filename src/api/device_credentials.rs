@@ -0,0 +1,174 @@
+use crate::client::ManagementClient;
+use crate::error::{Auth0Error, Result};
+use crate::types::device_credentials::{
+    CreatePublicKeyRequest, CreatedDeviceCredential, DeviceCredential, DeviceCredentialsPage,
+    ListDeviceCredentialsParams,
+};
+
+/// API operations for Auth0 Device Credentials.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{ListDeviceCredentialsParams, UserId};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// // Revoke every device credential held by a departing user
+/// let params = ListDeviceCredentialsParams {
+///     user_id: Some(UserId::new("auth0|123")),
+///     ..Default::default()
+/// };
+/// for credential in client.device_credentials().list_all(Some(params)).await? {
+///     client.device_credentials().delete(&credential.id).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Device Credentials API documentation](https://auth0.com/docs/api/management/v2/device-credentials/get-device-credentials)
+/// for detailed information on device credentials.
+pub struct DeviceCredentialsApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> DeviceCredentialsApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List device credentials, optionally filtered by user, client or type.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering, pagination, and field selection.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of device credentials matching the criteria.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/device-credentials/get-device-credentials>
+    pub async fn list(
+        &self,
+        params: Option<ListDeviceCredentialsParams>,
+    ) -> Result<Vec<DeviceCredential>> {
+        let mut url = self.client.base_url().join("api/v2/device-credentials")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// List device credentials with pagination totals.
+    ///
+    /// This method automatically sets `include_totals` to `true` and returns
+    /// a paginated response with total count information.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering, pagination, and field selection.
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated response containing device credentials and pagination metadata.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/device-credentials/get-device-credentials>
+    pub async fn list_with_totals(
+        &self,
+        params: Option<ListDeviceCredentialsParams>,
+    ) -> Result<DeviceCredentialsPage> {
+        let mut url = self.client.base_url().join("api/v2/device-credentials")?;
+
+        let p = params.unwrap_or_default();
+        let mut query = serde_urlencoded::to_string(&p)
+            .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+        if query.is_empty() {
+            query = "include_totals=true".to_string();
+        } else {
+            query.push_str("&include_totals=true");
+        }
+        url.set_query(Some(&query));
+
+        self.client.get(url).await
+    }
+
+    /// List every device credential matching the filters, fetching page after page.
+    ///
+    /// Starts at `params.page` (or the first page) and keeps `params.per_page`
+    /// (or 50) results per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering and field selection.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/device-credentials/get-device-credentials>
+    pub async fn list_all(
+        &self,
+        params: Option<ListDeviceCredentialsParams>,
+    ) -> Result<Vec<DeviceCredential>> {
+        let mut params = params.unwrap_or_default();
+        params.per_page = Some(params.per_page.unwrap_or(50));
+        let mut page = params.page.unwrap_or(0);
+        let mut credentials = Vec::new();
+
+        loop {
+            params.page = Some(page);
+            let result = self.list_with_totals(Some(params.clone())).await?;
+            let fetched = result.device_credentials.len();
+            credentials.extend(result.device_credentials);
+
+            if fetched == 0 || result.start as usize + fetched >= result.total as usize {
+                return Ok(credentials);
+            }
+            page += 1;
+        }
+    }
+
+    /// Register a public key for a device.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Device name and ID, the public key and an optional client.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ID of the new credential.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/device-credentials/post-device-credentials>
+    pub async fn create_public_key(
+        &self,
+        request: CreatePublicKeyRequest,
+    ) -> Result<CreatedDeviceCredential> {
+        let url = self.client.base_url().join("api/v2/device-credentials")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Delete a device credential, revoking it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The device credential ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/device-credentials/delete-device-credentials-by-id>
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/device-credentials/{}",
+            urlencoding::encode(id)
+        ))?;
+
+        self.client.delete(url).await
+    }
+}
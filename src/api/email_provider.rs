@@ -0,0 +1,99 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::emails::{CreateEmailProviderRequest, EmailProvider, UpdateEmailProviderRequest};
+
+/// Fields requested when reading the provider; Auth0 returns only `name` and `enabled`
+/// otherwise.
+const PROVIDER_FIELDS: &str = "name,enabled,credentials,settings,default_from_address";
+
+/// API operations for the Auth0 Email Provider.
+///
+/// A tenant has at most one email provider, used for all emails Auth0 sends.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{CreateEmailProviderRequest, EmailProviderCredentials, SesCredentials};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let credentials = EmailProviderCredentials::Ses(SesCredentials {
+///     access_key_id: Some("AKIA...".into()),
+///     secret_access_key: Some("secret".into()),
+///     region: Some("eu-west-1".into()),
+/// });
+/// let provider = client
+///     .email_provider()
+///     .create(CreateEmailProviderRequest::new(credentials))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Emails API documentation](https://auth0.com/docs/api/management/v2/emails/get-provider)
+/// for detailed information on email providers.
+pub struct EmailProviderApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> EmailProviderApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the email provider, including its non-secret credentials and settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/emails/get-provider>
+    pub async fn get(&self) -> Result<EmailProvider> {
+        let mut url = self.client.base_url().join("api/v2/emails/provider")?;
+        url.query_pairs_mut().append_pair("fields", PROVIDER_FIELDS);
+        self.client.get(url).await
+    }
+
+    /// Configure the email provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The provider, its credentials and the default sender.
+    ///
+    /// # Returns
+    ///
+    /// Returns the configured provider.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/emails/post-provider>
+    pub async fn create(&self, request: CreateEmailProviderRequest) -> Result<EmailProvider> {
+        let url = self.client.base_url().join("api/v2/emails/provider")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Update the email provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Fields to update. Only provided fields are modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated provider.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/emails/patch-provider>
+    pub async fn update(&self, request: UpdateEmailProviderRequest) -> Result<EmailProvider> {
+        let url = self.client.base_url().join("api/v2/emails/provider")?;
+        self.client.patch(url, &request).await
+    }
+
+    /// Delete the email provider. Auth0 falls back to its built-in test provider.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/emails/delete-provider>
+    pub async fn delete(&self) -> Result<()> {
+        let url = self.client.base_url().join("api/v2/emails/provider")?;
+        self.client.delete(url).await
+    }
+}
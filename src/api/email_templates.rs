@@ -0,0 +1,99 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::emails::{
+    CreateEmailTemplateRequest, EmailTemplate, EmailTemplateName, UpdateEmailTemplateRequest,
+};
+
+/// API operations for Auth0 Email Templates.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{EmailTemplateName, Patch, UpdateEmailTemplateRequest};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let request = UpdateEmailTemplateRequest {
+///     subject: Patch::Value("Welcome to Example".into()),
+///     ..Default::default()
+/// };
+/// client
+///     .email_templates()
+///     .update(EmailTemplateName::WelcomeEmail, request)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Email Templates API documentation](https://auth0.com/docs/api/management/v2/email-templates/get-email-templates-by-template-name)
+/// for detailed information on email templates.
+pub struct EmailTemplatesApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> EmailTemplatesApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// Get an email template.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The template to get.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/email-templates/get-email-templates-by-template-name>
+    pub async fn get(&self, name: EmailTemplateName) -> Result<EmailTemplate> {
+        let url = self.template_url(name)?;
+        self.client.get(url).await
+    }
+
+    /// Create an email template.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The template name, content and sender.
+    ///
+    /// # Returns
+    ///
+    /// Returns the created template.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/email-templates/post-email-templates>
+    pub async fn create(&self, request: CreateEmailTemplateRequest) -> Result<EmailTemplate> {
+        let url = self.client.base_url().join("api/v2/email-templates")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Update an email template.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The template to update.
+    /// * `request` - Fields to update. Only provided fields are modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated template.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/email-templates/patch-email-templates-by-template-name>
+    pub async fn update(
+        &self,
+        name: EmailTemplateName,
+        request: UpdateEmailTemplateRequest,
+    ) -> Result<EmailTemplate> {
+        let url = self.template_url(name)?;
+        self.client.patch(url, &request).await
+    }
+
+    fn template_url(&self, name: EmailTemplateName) -> Result<url::Url> {
+        Ok(self
+            .client
+            .base_url()
+            .join(&format!("api/v2/email-templates/{}", name.as_str()))?)
+    }
+}
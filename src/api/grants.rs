@@ -0,0 +1,153 @@
+use crate::client::ManagementClient;
+use crate::error::{Auth0Error, Result};
+use crate::types::UserId;
+use crate::types::grants::{Grant, GrantsPage, ListGrantsParams};
+
+/// API operations for Auth0 Grants.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::UserId;
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// // Withdraw every consent a departing user has given
+/// client.grants().delete_by_user(UserId::new("auth0|123")).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Grants API documentation](https://auth0.com/docs/api/management/v2/grants/get-grants)
+/// for detailed information on grants.
+pub struct GrantsApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> GrantsApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List grants, optionally filtered by user, client or audience.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering and pagination.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of grants matching the criteria.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/grants/get-grants>
+    pub async fn list(&self, params: Option<ListGrantsParams>) -> Result<Vec<Grant>> {
+        let mut url = self.client.base_url().join("api/v2/grants")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// List grants with pagination totals.
+    ///
+    /// This method automatically sets `include_totals` to `true` and returns
+    /// a paginated response with total count information.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering and pagination.
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated response containing grants and pagination metadata.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/grants/get-grants>
+    pub async fn list_with_totals(&self, params: Option<ListGrantsParams>) -> Result<GrantsPage> {
+        let mut url = self.client.base_url().join("api/v2/grants")?;
+
+        let p = params.unwrap_or_default();
+        let mut query = serde_urlencoded::to_string(&p)
+            .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+        if query.is_empty() {
+            query = "include_totals=true".to_string();
+        } else {
+            query.push_str("&include_totals=true");
+        }
+        url.set_query(Some(&query));
+
+        self.client.get(url).await
+    }
+
+    /// List every grant matching the filters, fetching page after page.
+    ///
+    /// Starts at `params.page` (or the first page) and keeps `params.per_page`
+    /// (or 50) results per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/grants/get-grants>
+    pub async fn list_all(&self, params: Option<ListGrantsParams>) -> Result<Vec<Grant>> {
+        let mut params = params.unwrap_or_default();
+        params.per_page = Some(params.per_page.unwrap_or(50));
+        let mut page = params.page.unwrap_or(0);
+        let mut grants = Vec::new();
+
+        loop {
+            params.page = Some(page);
+            let result = self.list_with_totals(Some(params.clone())).await?;
+            let fetched = result.grants.len();
+            grants.extend(result.grants);
+
+            if fetched == 0 || result.start as usize + fetched >= result.total as usize {
+                return Ok(grants);
+            }
+            page += 1;
+        }
+    }
+
+    /// Delete a grant by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The grant ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/grants/delete-grants-by-id>
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        let url = self
+            .client
+            .base_url()
+            .join(&format!("api/v2/grants/{}", urlencoding::encode(id)))?;
+
+        self.client.delete(url).await
+    }
+
+    /// Delete every grant held by a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/grants/delete-grants-by-user-id>
+    pub async fn delete_by_user(&self, user_id: UserId) -> Result<()> {
+        let mut url = self.client.base_url().join("api/v2/grants")?;
+        url.query_pairs_mut()
+            .append_pair("user_id", user_id.as_str());
+
+        self.client.delete(url).await
+    }
+}
@@ -0,0 +1,346 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::guardian::{
+    ApnsConfig, CreateEnrollmentTicketRequest, EnrollmentTicket, FcmConfig, GuardianFactor,
+    GuardianFactorName, MfaPolicy, PhoneProvider, PushProvider, SnsConfig, TwilioConfig,
+};
+use crate::types::mfa::{Enrollment, PhoneDeliveryMethod};
+
+/// Body of the `selected-provider` endpoints.
+#[derive(Serialize, Deserialize)]
+struct SelectedProvider<P> {
+    provider: P,
+}
+
+/// Body of the phone `message-types` endpoint.
+#[derive(Serialize, Deserialize)]
+struct MessageTypes {
+    message_types: Vec<PhoneDeliveryMethod>,
+}
+
+/// API operations for Auth0 Guardian (multi-factor authentication).
+///
+/// Covers which factors are enabled, when MFA is required, how SMS, voice and push
+/// messages are delivered, and enrollment tickets.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{GuardianFactorName, MfaPolicy, PhoneProvider, TwilioConfig};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let guardian = client.guardian();
+/// guardian.set_factor_enabled(GuardianFactorName::Sms, true).await?;
+/// guardian.set_phone_provider(PhoneProvider::Twilio).await?;
+/// guardian
+///     .set_twilio_config(TwilioConfig {
+///         messaging_service_sid: Some("MG123".into()),
+///         sid: Some("AC123".into()),
+///         auth_token: Some("twilio-token".into()),
+///         ..Default::default()
+///     })
+///     .await?;
+/// guardian.set_policies(vec![MfaPolicy::AllApplications]).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Guardian API documentation](https://auth0.com/docs/api/management/v2/guardian/get-factors)
+/// for detailed information on MFA configuration.
+pub struct GuardianApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> GuardianApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List the MFA factors and whether each is enabled.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/get-factors>
+    pub async fn list_factors(&self) -> Result<Vec<GuardianFactor>> {
+        let url = self.guardian_url("factors")?;
+        self.client.get(url).await
+    }
+
+    /// Enable or disable an MFA factor.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The factor to change.
+    /// * `enabled` - Whether users can enroll and authenticate with the factor.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/put-factors-by-name>
+    pub async fn set_factor_enabled(
+        &self,
+        factor: GuardianFactorName,
+        enabled: bool,
+    ) -> Result<()> {
+        let url =
+            self.guardian_url(&format!("factors/{}", urlencoding::encode(factor.as_str())))?;
+        self.client
+            .put_no_content(url, &serde_json::json!({ "enabled": enabled }))
+            .await
+    }
+
+    /// Get the MFA policies.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/get-policies>
+    pub async fn get_policies(&self) -> Result<Vec<MfaPolicy>> {
+        let url = self.guardian_url("policies")?;
+        self.client.get(url).await
+    }
+
+    /// Set the MFA policies.
+    ///
+    /// # Arguments
+    ///
+    /// * `policies` - The new policies. An empty list removes the requirement.
+    ///
+    /// # Returns
+    ///
+    /// Returns the policies now in effect.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/put-policies>
+    pub async fn set_policies(&self, policies: Vec<MfaPolicy>) -> Result<Vec<MfaPolicy>> {
+        let url = self.guardian_url("policies")?;
+        self.client.put(url, &policies).await
+    }
+
+    /// Get the provider delivering SMS and voice messages.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/get-phone-providers>
+    pub async fn get_phone_provider(&self) -> Result<PhoneProvider> {
+        let url = self.guardian_url("factors/phone/selected-provider")?;
+        let selected: SelectedProvider<PhoneProvider> = self.client.get(url).await?;
+        Ok(selected.provider)
+    }
+
+    /// Select the provider delivering SMS and voice messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider to use.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/put-phone-providers>
+    pub async fn set_phone_provider(&self, provider: PhoneProvider) -> Result<PhoneProvider> {
+        let url = self.guardian_url("factors/phone/selected-provider")?;
+        let selected: SelectedProvider<PhoneProvider> =
+            self.client.put(url, &SelectedProvider { provider }).await?;
+        Ok(selected.provider)
+    }
+
+    /// Get the message types offered by the phone factor.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/get-message-types>
+    pub async fn get_phone_message_types(&self) -> Result<Vec<PhoneDeliveryMethod>> {
+        let url = self.guardian_url("factors/phone/message-types")?;
+        let types: MessageTypes = self.client.get(url).await?;
+        Ok(types.message_types)
+    }
+
+    /// Set the message types offered by the phone factor.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_types` - `sms`, `voice`, or both.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/put-message-types>
+    pub async fn set_phone_message_types(
+        &self,
+        message_types: Vec<PhoneDeliveryMethod>,
+    ) -> Result<Vec<PhoneDeliveryMethod>> {
+        let url = self.guardian_url("factors/phone/message-types")?;
+        let types: MessageTypes = self
+            .client
+            .put(url, &MessageTypes { message_types })
+            .await?;
+        Ok(types.message_types)
+    }
+
+    /// Get the Twilio configuration of the phone factor.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/get-twilio-factor-provider>
+    pub async fn get_twilio_config(&self) -> Result<TwilioConfig> {
+        let url = self.guardian_url("factors/phone/providers/twilio")?;
+        self.client.get(url).await
+    }
+
+    /// Set the Twilio configuration of the phone factor.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Twilio account and sender.
+    ///
+    /// # Returns
+    ///
+    /// Returns the configuration now in effect.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/put-twilio>
+    pub async fn set_twilio_config(&self, config: TwilioConfig) -> Result<TwilioConfig> {
+        let url = self.guardian_url("factors/phone/providers/twilio")?;
+        self.client.put(url, &config).await
+    }
+
+    /// Get the provider delivering push notifications.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/get-pn-providers>
+    pub async fn get_push_provider(&self) -> Result<PushProvider> {
+        let url = self.guardian_url("factors/push-notification/selected-provider")?;
+        let selected: SelectedProvider<PushProvider> = self.client.get(url).await?;
+        Ok(selected.provider)
+    }
+
+    /// Select the provider delivering push notifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider to use.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/put-pn-providers>
+    pub async fn set_push_provider(&self, provider: PushProvider) -> Result<PushProvider> {
+        let url = self.guardian_url("factors/push-notification/selected-provider")?;
+        let selected: SelectedProvider<PushProvider> =
+            self.client.put(url, &SelectedProvider { provider }).await?;
+        Ok(selected.provider)
+    }
+
+    /// Get the Amazon SNS configuration for push notifications.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/get-sns>
+    pub async fn get_sns_config(&self) -> Result<SnsConfig> {
+        let url = self.guardian_url("factors/push-notification/providers/sns")?;
+        self.client.get(url).await
+    }
+
+    /// Set the Amazon SNS configuration for push notifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - AWS credentials and platform application ARNs.
+    ///
+    /// # Returns
+    ///
+    /// Returns the configuration now in effect.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/put-sns>
+    pub async fn set_sns_config(&self, config: SnsConfig) -> Result<SnsConfig> {
+        let url = self.guardian_url("factors/push-notification/providers/sns")?;
+        self.client.put(url, &config).await
+    }
+
+    /// Set the APNs configuration for direct push notifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Bundle ID, certificate and environment.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/put-apns>
+    pub async fn set_apns_config(&self, config: ApnsConfig) -> Result<()> {
+        let url = self.guardian_url("factors/push-notification/providers/apns")?;
+        self.client.put_no_content(url, &config).await
+    }
+
+    /// Set the FCM configuration for direct push notifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - FCM server key.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/put-fcm>
+    pub async fn set_fcm_config(&self, config: FcmConfig) -> Result<()> {
+        let url = self.guardian_url("factors/push-notification/providers/fcm")?;
+        self.client.put_no_content(url, &config).await
+    }
+
+    /// Create a ticket a user can open to enroll in MFA.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The user and optional factor and delivery settings.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ticket ID and URL.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/post-ticket>
+    pub async fn create_enrollment_ticket(
+        &self,
+        request: CreateEnrollmentTicketRequest,
+    ) -> Result<EnrollmentTicket> {
+        let url = self.guardian_url("enrollments/ticket")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Get an MFA enrollment.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The enrollment's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/get-enrollments-by-id>
+    pub async fn get_enrollment(&self, id: &str) -> Result<Enrollment> {
+        let url = self.guardian_url(&format!("enrollments/{}", urlencoding::encode(id)))?;
+        self.client.get(url).await
+    }
+
+    /// Delete an MFA enrollment. The user must enroll again to use the factor.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The enrollment's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/guardian/delete-enrollments-by-id>
+    pub async fn delete_enrollment(&self, id: &str) -> Result<()> {
+        let url = self.guardian_url(&format!("enrollments/{}", urlencoding::encode(id)))?;
+        self.client.delete(url).await
+    }
+
+    fn guardian_url(&self, path: &str) -> Result<url::Url> {
+        Ok(self
+            .client
+            .base_url()
+            .join(&format!("api/v2/guardian/{}", path))?)
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+
+use secrecy::{ExposeSecret, SecretString};
+
+use crate::client::ManagementClient;
+use crate::error::{Auth0Error, Result};
+use crate::types::hooks::{CreateHookRequest, Hook, HooksPage, ListHooksParams, UpdateHookRequest};
+
+/// API operations for Auth0 Hooks.
+///
+/// Hooks are deprecated; new extensibility code should use the Actions API, available
+/// with the `actions` feature.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{HookTrigger, ListHooksParams};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// // Inventory the hooks left to migrate to Actions
+/// let params = ListHooksParams {
+///     trigger_id: Some(HookTrigger::PreUserRegistration),
+///     ..Default::default()
+/// };
+/// for hook in client.hooks().list(Some(params)).await? {
+///     let secrets = client.hooks().get_secrets(&hook.id).await?;
+///     println!("{:?}: {} secrets", hook.name, secrets.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Hooks API documentation](https://auth0.com/docs/api/management/v2/hooks/get-hooks)
+/// for detailed information on hooks.
+pub struct HooksApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> HooksApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List hooks, optionally filtered by trigger or enabled state.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering, pagination, and field selection.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of hooks matching the criteria.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/get-hooks>
+    pub async fn list(&self, params: Option<ListHooksParams>) -> Result<Vec<Hook>> {
+        let mut url = self.client.base_url().join("api/v2/hooks")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// List hooks with pagination totals.
+    ///
+    /// This method automatically sets `include_totals` to `true` and returns
+    /// a paginated response with total count information.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering, pagination, and field selection.
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated response containing hooks and pagination metadata.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/get-hooks>
+    pub async fn list_with_totals(&self, params: Option<ListHooksParams>) -> Result<HooksPage> {
+        let mut url = self.client.base_url().join("api/v2/hooks")?;
+
+        let p = params.unwrap_or_default();
+        let mut query = serde_urlencoded::to_string(&p)
+            .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+        if query.is_empty() {
+            query = "include_totals=true".to_string();
+        } else {
+            query.push_str("&include_totals=true");
+        }
+        url.set_query(Some(&query));
+
+        self.client.get(url).await
+    }
+
+    /// Get a hook by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The hook ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/get-hooks-by-id>
+    pub async fn get(&self, id: &str) -> Result<Hook> {
+        let url = self.hook_url(id, "")?;
+        self.client.get(url).await
+    }
+
+    /// Create a new hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Hook name, script, trigger and optional enabled state and dependencies.
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly created hook.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/post-hooks>
+    pub async fn create(&self, request: CreateHookRequest) -> Result<Hook> {
+        let url = self.client.base_url().join("api/v2/hooks")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Update a hook by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The hook ID.
+    /// * `request` - Fields to update. Only provided fields are modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated hook.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/patch-hooks-by-id>
+    pub async fn update(&self, id: &str, request: UpdateHookRequest) -> Result<Hook> {
+        let url = self.hook_url(id, "")?;
+        self.client.patch(url, &request).await
+    }
+
+    /// Delete a hook by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The hook ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/delete-hooks-by-id>
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        let url = self.hook_url(id, "")?;
+        self.client.delete(url).await
+    }
+
+    /// Get the names of a hook's secrets.
+    ///
+    /// Auth0 never returns secret values; each value in the map is a placeholder.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The hook ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/get-secrets>
+    pub async fn get_secrets(&self, id: &str) -> Result<HashMap<String, String>> {
+        let url = self.hook_url(id, "/secrets")?;
+        self.client.get(url).await
+    }
+
+    /// Add secrets to a hook. Fails if any of the names already exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The hook ID.
+    /// * `secrets` - Secret values keyed by name.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/post-secrets>
+    pub async fn add_secrets(
+        &self,
+        id: &str,
+        secrets: &HashMap<String, SecretString>,
+    ) -> Result<()> {
+        let url = self.hook_url(id, "/secrets")?;
+        self.client.post_no_content(url, &expose(secrets)).await
+    }
+
+    /// Update existing secrets of a hook. Fails if any of the names does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The hook ID.
+    /// * `secrets` - New secret values keyed by name.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/patch-secrets>
+    pub async fn update_secrets(
+        &self,
+        id: &str,
+        secrets: &HashMap<String, SecretString>,
+    ) -> Result<()> {
+        let url = self.hook_url(id, "/secrets")?;
+        self.client.patch_no_content(url, &expose(secrets)).await
+    }
+
+    /// Delete secrets from a hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The hook ID.
+    /// * `names` - Names of the secrets to delete.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/hooks/delete-secrets>
+    pub async fn delete_secrets(&self, id: &str, names: &[&str]) -> Result<()> {
+        let url = self.hook_url(id, "/secrets")?;
+        self.client.delete_with_body(url, &names).await
+    }
+
+    fn hook_url(&self, id: &str, suffix: &str) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/hooks/{}{}",
+            urlencoding::encode(id),
+            suffix
+        ))?)
+    }
+}
+
+fn expose(secrets: &HashMap<String, SecretString>) -> HashMap<&str, &str> {
+    secrets
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.expose_secret()))
+        .collect()
+}
@@ -0,0 +1,113 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::keys::{Jwks, SigningKey, SigningKeyCert};
+
+/// API operations for Auth0 signing keys.
+///
+/// # Examples
+///
+/// ```ignore
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// // Publish the next key to downstream services before rotating to it
+/// let jwks = client.keys().jwks().await?;
+/// println!("{}", serde_json::to_string_pretty(&jwks)?);
+///
+/// let rotated = client.keys().rotate().await?;
+/// println!("now signing with {}", rotated.kid);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Keys API documentation](https://auth0.com/docs/api/management/v2/keys/get-signing-keys)
+/// for detailed information on signing keys.
+pub struct KeysApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> KeysApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List the tenant's signing keys, including revoked ones.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/keys/get-signing-keys>
+    pub async fn list(&self) -> Result<Vec<SigningKey>> {
+        let url = self.client.base_url().join("api/v2/keys/signing")?;
+        self.client.get(url).await
+    }
+
+    /// Get a signing key by its key ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `kid` - The key ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/keys/get-signing-key>
+    pub async fn get(&self, kid: &str) -> Result<SigningKey> {
+        let url = self.key_url(kid, "")?;
+        self.client.get(url).await
+    }
+
+    /// Rotate the signing key.
+    ///
+    /// The `next` key becomes `current`, the current key becomes `previous`, and a new
+    /// `next` key is generated.
+    ///
+    /// # Returns
+    ///
+    /// Returns the key ID and certificate of the new `next` key.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/keys/post-signing-keys>
+    pub async fn rotate(&self) -> Result<SigningKeyCert> {
+        let url = self.client.base_url().join("api/v2/keys/signing/rotate")?;
+        self.client.post(url, &serde_json::json!({})).await
+    }
+
+    /// Revoke a signing key. Tokens it signed can no longer be verified.
+    ///
+    /// Only the `previous` key can be revoked; rotate first to revoke the current key.
+    ///
+    /// # Arguments
+    ///
+    /// * `kid` - The key ID.
+    ///
+    /// # Returns
+    ///
+    /// Returns the key ID and certificate of the revoked key.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/keys/put-signing-keys>
+    pub async fn revoke(&self, kid: &str) -> Result<SigningKeyCert> {
+        let url = self.key_url(kid, "/revoke")?;
+        self.client.put(url, &serde_json::json!({})).await
+    }
+
+    /// List the signing keys and compute their JSON Web Key Set.
+    ///
+    /// Revoked keys are left out, so the result matches the tenant's published
+    /// `/.well-known/jwks.json` and can be pinned in services that verify tokens offline.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::InvalidCertificate` if a key's certificate cannot be decoded.
+    pub async fn jwks(&self) -> Result<Jwks> {
+        let keys = self.list().await?;
+        Jwks::from_signing_keys(&keys)
+    }
+
+    fn key_url(&self, kid: &str, suffix: &str) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/keys/signing/{}{}",
+            urlencoding::encode(kid),
+            suffix
+        ))?)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::log_streams::{CreateLogStreamRequest, LogStream, UpdateLogStreamRequest};
+
+/// API operations for Auth0 Log Streams.
+///
+/// Log streams push tenant log events to an external sink such as a webhook, Datadog or
+/// Amazon EventBridge as they happen.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{
+///     CreateLogStreamRequest, DatadogRegion, DatadogSink, LogEventCategory, LogStreamFilter,
+///     LogStreamSink,
+/// };
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let sink = LogStreamSink::Datadog(DatadogSink {
+///     datadog_region: DatadogRegion::Eu,
+///     datadog_api_key: Some("dd-api-key".into()),
+/// });
+/// let mut request = CreateLogStreamRequest::new("datadog-prod", sink);
+/// request.filters = Some(vec![LogStreamFilter::category(LogEventCategory::AuthLoginFail)]);
+///
+/// let stream = client.log_streams().create(request).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Log Streams API documentation](https://auth0.com/docs/api/management/v2/log-streams/get-log-streams)
+/// for detailed information on log streams.
+pub struct LogStreamsApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> LogStreamsApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List all log streams.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/log-streams/get-log-streams>
+    pub async fn list(&self) -> Result<Vec<LogStream>> {
+        let url = self.client.base_url().join("api/v2/log-streams")?;
+        self.client.get(url).await
+    }
+
+    /// Get a log stream by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The log stream's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/log-streams/get-log-streams-by-id>
+    pub async fn get(&self, id: &str) -> Result<LogStream> {
+        let url = self.stream_url(id)?;
+        self.client.get(url).await
+    }
+
+    /// Create a log stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The stream's name, sink and optional category filters.
+    ///
+    /// # Returns
+    ///
+    /// Returns the created log stream.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/log-streams/post-log-streams>
+    pub async fn create(&self, request: CreateLogStreamRequest) -> Result<LogStream> {
+        let url = self.client.base_url().join("api/v2/log-streams")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Update a log stream, e.g. to pause it or change its filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The log stream's unique identifier.
+    /// * `request` - Fields to update. Only provided fields are modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated log stream.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/log-streams/patch-log-streams-by-id>
+    pub async fn update(&self, id: &str, request: UpdateLogStreamRequest) -> Result<LogStream> {
+        let url = self.stream_url(id)?;
+        self.client.patch(url, &request).await
+    }
+
+    /// Delete a log stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The log stream's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/log-streams/delete-log-streams-by-id>
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        let url = self.stream_url(id)?;
+        self.client.delete(url).await
+    }
+
+    fn stream_url(&self, id: &str) -> Result<url::Url> {
+        Ok(self
+            .client
+            .base_url()
+            .join(&format!("api/v2/log-streams/{}", urlencoding::encode(id)))?)
+    }
+}
@@ -1,6 +1,21 @@
 #[cfg(feature = "users")]
 pub mod users;
 
+#[cfg(feature = "users")]
+pub mod user_blocks;
+
+#[cfg(feature = "users")]
+pub mod sessions;
+
+#[cfg(feature = "users")]
+pub mod refresh_tokens;
+
+#[cfg(feature = "device_credentials")]
+pub mod device_credentials;
+
+#[cfg(feature = "grants")]
+pub mod grants;
+
 #[cfg(feature = "clients")]
 pub mod clients;
 
@@ -9,3 +24,45 @@ pub mod connections;
 
 #[cfg(feature = "logs")]
 pub mod logs;
+
+#[cfg(feature = "actions")]
+pub mod actions;
+
+#[cfg(feature = "rules")]
+pub mod rules;
+
+#[cfg(feature = "rules")]
+pub mod rules_configs;
+
+#[cfg(feature = "log_streams")]
+pub mod log_streams;
+
+#[cfg(feature = "tenants")]
+pub mod tenants;
+
+#[cfg(feature = "emails")]
+pub mod email_provider;
+
+#[cfg(feature = "emails")]
+pub mod email_templates;
+
+#[cfg(feature = "branding")]
+pub mod branding;
+
+#[cfg(feature = "branding")]
+pub mod prompts;
+
+#[cfg(feature = "custom_domains")]
+pub mod custom_domains;
+
+#[cfg(feature = "guardian")]
+pub mod guardian;
+
+#[cfg(feature = "attack_protection")]
+pub mod attack_protection;
+
+#[cfg(feature = "keys")]
+pub mod keys;
+
+#[cfg(feature = "hooks")]
+pub mod hooks;
@@ -0,0 +1,116 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::prompts::{PromptCustomText, PromptLanguage, PromptName, PromptSettings};
+
+/// API operations for Auth0 Prompts.
+///
+/// Prompts are the Universal Login screens. Their text can be customized per language.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{PromptCustomText, PromptLanguage, PromptName};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let mut text = PromptCustomText::new();
+/// text.entry("login".into())
+///     .or_default()
+///     .insert("title".into(), "Welcome back to Example".into());
+///
+/// client
+///     .prompts()
+///     .set_custom_text(PromptName::Login, PromptLanguage::English, &text)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Prompts API documentation](https://auth0.com/docs/api/management/v2/prompts/get-prompts)
+/// for detailed information on prompts.
+pub struct PromptsApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> PromptsApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the tenant's prompt settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/prompts/get-prompts>
+    pub async fn get_settings(&self) -> Result<PromptSettings> {
+        let url = self.client.base_url().join("api/v2/prompts")?;
+        self.client.get(url).await
+    }
+
+    /// Update the tenant's prompt settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Settings to update. Fields left as `None` are not modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/prompts/patch-prompts>
+    pub async fn update_settings(&self, settings: PromptSettings) -> Result<PromptSettings> {
+        let url = self.client.base_url().join("api/v2/prompts")?;
+        self.client.patch(url, &settings).await
+    }
+
+    /// Get the custom text of a prompt in one language.
+    ///
+    /// # Arguments
+    ///
+    /// * `prompt` - The prompt.
+    /// * `language` - The language.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/prompts/get-custom-text-by-language>
+    pub async fn get_custom_text(
+        &self,
+        prompt: PromptName,
+        language: PromptLanguage,
+    ) -> Result<PromptCustomText> {
+        let url = self.custom_text_url(&prompt, &language)?;
+        self.client.get(url).await
+    }
+
+    /// Set the custom text of a prompt in one language.
+    ///
+    /// The given text replaces all existing custom text for the prompt and language.
+    ///
+    /// # Arguments
+    ///
+    /// * `prompt` - The prompt.
+    /// * `language` - The language.
+    /// * `text` - Custom text keyed by screen name and then text key.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/prompts/put-custom-text-by-language>
+    pub async fn set_custom_text(
+        &self,
+        prompt: PromptName,
+        language: PromptLanguage,
+        text: &PromptCustomText,
+    ) -> Result<()> {
+        let url = self.custom_text_url(&prompt, &language)?;
+        self.client.put_no_content(url, text).await
+    }
+
+    fn custom_text_url(&self, prompt: &PromptName, language: &PromptLanguage) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/prompts/{}/custom-text/{}",
+            urlencoding::encode(prompt.as_str()),
+            urlencoding::encode(language.as_str())
+        ))?)
+    }
+}
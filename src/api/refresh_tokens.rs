@@ -0,0 +1,72 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::refresh_tokens::RefreshToken;
+
+/// API operations for Auth0 Refresh Tokens.
+///
+/// Use [`UsersApi::list_refresh_tokens`](crate::api::users::UsersApi::list_refresh_tokens)
+/// to find the refresh tokens issued to a user.
+///
+/// # Examples
+///
+/// ```ignore
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let token = client.refresh_tokens().get("rt_123").await?;
+/// println!("Issued to: {:?}", token.client_id);
+///
+/// client.refresh_tokens().delete("rt_123").await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Refresh Tokens API documentation](https://auth0.com/docs/api/management/v2/refresh-tokens/get-refresh-token)
+/// for detailed information on refresh tokens.
+pub struct RefreshTokensApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> RefreshTokensApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// Get a refresh token's metadata by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The refresh token ID.
+    ///
+    /// # Returns
+    ///
+    /// Returns the refresh token details if found.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/refresh-tokens/get-refresh-token>
+    pub async fn get(&self, id: &str) -> Result<RefreshToken> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/refresh-tokens/{}",
+            urlencoding::encode(id)
+        ))?;
+
+        self.client.get(url).await
+    }
+
+    /// Revoke a refresh token.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The refresh token ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/refresh-tokens/delete-refresh-token>
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/refresh-tokens/{}",
+            urlencoding::encode(id)
+        ))?;
+
+        self.client.delete(url).await
+    }
+}
@@ -0,0 +1,180 @@
+use crate::client::ManagementClient;
+use crate::error::{Auth0Error, Result};
+use crate::types::RuleId;
+use crate::types::rules::{CreateRuleRequest, ListRulesParams, Rule, RulesPage, UpdateRuleRequest};
+
+/// API operations for Auth0 Rules.
+///
+/// Rules are the legacy extensibility mechanism superseded by Actions. These endpoints
+/// are mainly useful for inspecting and migrating existing tenants.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{ListRulesParams, Patch, UpdateRuleRequest};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let params = ListRulesParams {
+///     enabled: Some(true),
+///     ..Default::default()
+/// };
+/// for rule in client.rules().list(Some(params)).await? {
+///     let disable = UpdateRuleRequest {
+///         enabled: Patch::Value(false),
+///         ..Default::default()
+///     };
+///     client.rules().update(rule.id, disable).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Rules API documentation](https://auth0.com/docs/api/management/v2#!/Rules/get_rules)
+/// for detailed information on rules and available operations.
+pub struct RulesApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> RulesApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List rules with optional pagination and filtering.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering, pagination, and field selection.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of rules matching the criteria.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Rules/get_rules>
+    pub async fn list(&self, params: Option<ListRulesParams>) -> Result<Vec<Rule>> {
+        let mut url = self.client.base_url().join("api/v2/rules")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// List rules with pagination totals.
+    ///
+    /// This method automatically sets `include_totals` to `true` and returns
+    /// a paginated response with total count information.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional query parameters for filtering, pagination, and field selection.
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated response containing rules and pagination metadata.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Rules/get_rules>
+    pub async fn list_with_totals(&self, params: Option<ListRulesParams>) -> Result<RulesPage> {
+        let mut url = self.client.base_url().join("api/v2/rules")?;
+
+        let p = params.unwrap_or_default();
+        let mut query = serde_urlencoded::to_string(&p)
+            .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+        if query.is_empty() {
+            query = "include_totals=true".to_string();
+        } else {
+            query.push_str("&include_totals=true");
+        }
+        url.set_query(Some(&query));
+
+        self.client.get(url).await
+    }
+
+    /// Get a rule by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The rule's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the rule details if found.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Rules/get_rules_by_id>
+    pub async fn get(&self, id: RuleId) -> Result<Rule> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/rules/{}",
+            urlencoding::encode(id.as_str())
+        ))?;
+
+        self.client.get(url).await
+    }
+
+    /// Create a new rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Rule name, script and optional order and enabled state.
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly created rule.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Rules/post_rules>
+    pub async fn create(&self, request: CreateRuleRequest) -> Result<Rule> {
+        let url = self.client.base_url().join("api/v2/rules")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Update a rule by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The rule's unique identifier.
+    /// * `request` - Rule fields to update. Only provided fields are modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated rule.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Rules/patch_rules_by_id>
+    pub async fn update(&self, id: RuleId, request: UpdateRuleRequest) -> Result<Rule> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/rules/{}",
+            urlencoding::encode(id.as_str())
+        ))?;
+
+        self.client.patch(url, &request).await
+    }
+
+    /// Delete a rule by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The rule's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Rules/delete_rules_by_id>
+    pub async fn delete(&self, id: RuleId) -> Result<()> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/rules/{}",
+            urlencoding::encode(id.as_str())
+        ))?;
+
+        self.client.delete(url).await
+    }
+}
@@ -0,0 +1,85 @@
+use secrecy::{ExposeSecret, SecretString};
+
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::rules::RulesConfigKey;
+
+/// API operations for Auth0 Rules Configs.
+///
+/// Rules configs are tenant-wide variables available to rules as `configuration.KEY`.
+/// Values are write-only: only the keys can be listed.
+///
+/// # Examples
+///
+/// ```ignore
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// client.rules_configs().set("SLACK_HOOK", "https://hooks.slack.com/...".into()).await?;
+/// let keys = client.rules_configs().list().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Rules Configs API documentation](https://auth0.com/docs/api/management/v2#!/Rules_Configs/get_rules_configs)
+/// for detailed information on rules configs.
+pub struct RulesConfigsApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> RulesConfigsApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// List the keys of all rules config variables.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Rules_Configs/get_rules_configs>
+    pub async fn list(&self) -> Result<Vec<RulesConfigKey>> {
+        let url = self.client.base_url().join("api/v2/rules-configs")?;
+        self.client.get(url).await
+    }
+
+    /// Set a rules config variable, creating or replacing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The variable name. Letters, digits, `_` and `-` only.
+    /// * `value` - The variable value.
+    ///
+    /// # Returns
+    ///
+    /// Returns the key that was set.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Rules_Configs/put_rules_configs_by_key>
+    pub async fn set(&self, key: &str, value: SecretString) -> Result<RulesConfigKey> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/rules-configs/{}",
+            urlencoding::encode(key)
+        ))?;
+
+        self.client
+            .put(url, &serde_json::json!({ "value": value.expose_secret() }))
+            .await
+    }
+
+    /// Delete a rules config variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The variable name.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Rules_Configs/delete_rules_configs_by_key>
+    pub async fn delete(&self, key: &str) -> Result<()> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/rules-configs/{}",
+            urlencoding::encode(key)
+        ))?;
+
+        self.client.delete(url).await
+    }
+}
@@ -0,0 +1,88 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::sessions::Session;
+
+/// API operations for Auth0 Sessions.
+///
+/// Use [`UsersApi::list_sessions`](crate::api::users::UsersApi::list_sessions) to find
+/// the sessions belonging to a user.
+///
+/// # Examples
+///
+/// ```ignore
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let session = client.sessions().get("sess_123").await?;
+/// println!("Last seen: {:?}", session.last_interacted_at);
+///
+/// client.sessions().delete("sess_123").await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Sessions API documentation](https://auth0.com/docs/api/management/v2/sessions/get-session)
+/// for detailed information on sessions.
+pub struct SessionsApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> SessionsApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// Get a session by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The session ID.
+    ///
+    /// # Returns
+    ///
+    /// Returns the session details if found.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/sessions/get-session>
+    pub async fn get(&self, id: &str) -> Result<Session> {
+        let url = self.session_url(id, "")?;
+        self.client.get(url).await
+    }
+
+    /// Delete a session, logging the user out of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The session ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/sessions/delete-session>
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        let url = self.session_url(id, "")?;
+        self.client.delete(url).await
+    }
+
+    /// Revoke a session and every refresh token issued in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The session ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/sessions/revoke-session>
+    pub async fn revoke(&self, id: &str) -> Result<()> {
+        let url = self.session_url(id, "/revoke")?;
+        self.client
+            .post_no_content(url, &serde_json::json!({}))
+            .await
+    }
+
+    fn session_url(&self, id: &str, suffix: &str) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/sessions/{}{}",
+            urlencoding::encode(id),
+            suffix
+        ))?)
+    }
+}
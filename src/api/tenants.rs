@@ -0,0 +1,62 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::tenants::{TenantSettings, UpdateTenantSettingsRequest};
+
+/// API operations for Auth0 Tenant Settings.
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::{Patch, UpdateTenantSettingsRequest};
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let settings = client.tenant_settings().get().await?;
+///
+/// let request = UpdateTenantSettingsRequest {
+///     support_email: Patch::Value("support@example.com".into()),
+///     ..Default::default()
+/// };
+/// client.tenant_settings().update(request).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 Tenants API documentation](https://auth0.com/docs/api/management/v2/tenants/tenant-settings-route)
+/// for detailed information on tenant settings.
+pub struct TenantSettingsApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> TenantSettingsApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the tenant's settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/tenants/tenant-settings-route>
+    pub async fn get(&self) -> Result<TenantSettings> {
+        let url = self.client.base_url().join("api/v2/tenants/settings")?;
+        self.client.get(url).await
+    }
+
+    /// Update the tenant's settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Settings to update. Only provided fields are modified.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated settings.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/tenants/patch-settings>
+    pub async fn update(&self, request: UpdateTenantSettingsRequest) -> Result<TenantSettings> {
+        let url = self.client.base_url().join("api/v2/tenants/settings")?;
+        self.client.patch(url, &request).await
+    }
+}
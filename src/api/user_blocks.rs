@@ -0,0 +1,111 @@
+use crate::client::ManagementClient;
+use crate::error::Result;
+use crate::types::UserId;
+use crate::types::user_blocks::UserBlocks;
+
+/// API operations for Auth0 User Blocks.
+///
+/// Inspects and removes the blocks created by brute-force protection. These are separate
+/// from the `blocked` flag set through [`UpdateUserRequest`](crate::UpdateUserRequest).
+///
+/// # Examples
+///
+/// ```ignore
+/// use auth0_mgmt_api::UserId;
+///
+/// # async fn example(client: auth0_mgmt_api::ManagementClient) -> auth0_mgmt_api::Result<()> {
+/// let blocks = client.user_blocks().get_by_identifier("user@example.com").await?;
+/// for entry in &blocks.blocked_for {
+///     println!("{} blocked from {:?}", entry.identifier, entry.ip);
+/// }
+///
+/// client.user_blocks().unblock(UserId::new("auth0|123456")).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See the [Auth0 User Blocks API documentation](https://auth0.com/docs/api/management/v2#!/User_Blocks/get_user_blocks)
+/// for detailed information on user blocks.
+pub struct UserBlocksApi<'a> {
+    client: &'a ManagementClient,
+}
+
+impl<'a> UserBlocksApi<'a> {
+    pub(crate) fn new(client: &'a ManagementClient) -> Self {
+        Self { client }
+    }
+
+    /// Get the blocks for an identifier (username, email or phone number).
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The username, email or phone number to look up.
+    ///
+    /// # Returns
+    ///
+    /// Returns the blocks for every IP address the identifier is blocked from.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/User_Blocks/get_user_blocks>
+    pub async fn get_by_identifier(&self, identifier: &str) -> Result<UserBlocks> {
+        let mut url = self.client.base_url().join("api/v2/user-blocks")?;
+        url.query_pairs_mut().append_pair("identifier", identifier);
+        self.client.get(url).await
+    }
+
+    /// Remove all blocks for an identifier (username, email or phone number).
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The username, email or phone number to unblock.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/User_Blocks/delete_user_blocks>
+    pub async fn unblock_by_identifier(&self, identifier: &str) -> Result<()> {
+        let mut url = self.client.base_url().join("api/v2/user-blocks")?;
+        url.query_pairs_mut().append_pair("identifier", identifier);
+        self.client.delete(url).await
+    }
+
+    /// Get the blocks for a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the blocks for every IP address the user is blocked from.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/User_Blocks/get_user_blocks_by_id>
+    pub async fn get(&self, id: UserId) -> Result<UserBlocks> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/user-blocks/{}",
+            urlencoding::encode(id.as_str())
+        ))?;
+
+        self.client.get(url).await
+    }
+
+    /// Remove all blocks for a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/User_Blocks/delete_user_blocks_by_id>
+    pub async fn unblock(&self, id: UserId) -> Result<()> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/user-blocks/{}",
+            urlencoding::encode(id.as_str())
+        ))?;
+
+        self.client.delete(url).await
+    }
+}
@@ -1,10 +1,18 @@
+use serde::{Serialize, de::DeserializeOwned};
+
 use crate::client::ManagementClient;
 use crate::error::{Auth0Error, Result};
-use crate::types::UserId;
 use crate::types::logs::{LogEvent, LogsPage};
+use crate::types::mfa::{
+    AuthenticationMethod, CreateAuthenticationMethodRequest, Enrollment, RecoveryCode,
+};
+use crate::types::refresh_tokens::RefreshTokensPage;
+use crate::types::sessions::SessionsPage;
 use crate::types::users::{
-    CreateUserRequest, GetUserLogsParams, ListUsersParams, UpdateUserRequest, User, UsersPage,
+    CreateUserRequest, GetUserLogsParams, Identity, LinkIdentityRequest, ListUsersParams,
+    TypedCreateUserRequest, TypedUpdateUserRequest, TypedUser, UpdateUserRequest, User, UsersPage,
 };
+use crate::types::{CheckpointParams, MetadataPatch, PaginationParams, UserId};
 
 /// API operations for Auth0 Users.
 ///
@@ -168,6 +176,45 @@ impl<'a> UsersApi<'a> {
         self.client.get(url).await
     }
 
+    /// Get a user by their user ID, deserializing metadata into typed structs.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the user with `app_metadata` as `A` and `user_metadata` as `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::{Metadata, UserId};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct AppMeta { plan: String }
+    ///
+    /// let user = client
+    ///     .users()
+    ///     .get_typed::<AppMeta, Metadata>(UserId::new("auth0|123456"))
+    ///     .await?;
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/get_users_by_id>
+    pub async fn get_typed<A: DeserializeOwned, U: DeserializeOwned>(
+        &self,
+        id: UserId,
+    ) -> Result<TypedUser<A, U>> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/users/{}",
+            urlencoding::encode(id.as_str())
+        ))?;
+
+        self.client.get(url).await
+    }
+
     /// Create a new user.
     ///
     /// # Arguments
@@ -200,6 +247,31 @@ impl<'a> UsersApi<'a> {
         self.client.post(url, &request).await
     }
 
+    /// Create a new user with typed metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - User creation parameters with typed `app_metadata` and `user_metadata`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly created user with metadata deserialized into the same types.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/post_users>
+    pub async fn create_typed<A, U>(
+        &self,
+        request: TypedCreateUserRequest<A, U>,
+    ) -> Result<TypedUser<A, U>>
+    where
+        A: Serialize + DeserializeOwned,
+        U: Serialize + DeserializeOwned,
+    {
+        let url = self.client.base_url().join("api/v2/users")?;
+        self.client.post(url, &request).await
+    }
+
     /// Update a user by their user ID.
     ///
     /// # Arguments
@@ -214,10 +286,10 @@ impl<'a> UsersApi<'a> {
     /// # Examples
     ///
     /// ```ignore
-    /// use auth0_mgmt_api::UserId;
+    /// use auth0_mgmt_api::{Patch, UserId};
     /// let update = UpdateUserRequest {
-    ///     email: Some("newemail@example.com".to_string()),
-    ///     blocked: Some(false),
+    ///     email: Patch::Value("newemail@example.com".to_string()),
+    ///     blocked: Patch::Value(false),
     ///     ..Default::default()
     /// };
     /// let updated = client.users().update(UserId::new("auth0|123456"), update).await?;
@@ -235,6 +307,111 @@ impl<'a> UsersApi<'a> {
         self.client.patch(url, &request).await
     }
 
+    /// Update a user by their user ID with typed metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    /// * `request` - User fields to update, with typed `app_metadata` and `user_metadata`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated user with metadata deserialized into the same types.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/patch_users_by_id>
+    pub async fn update_typed<A, U>(
+        &self,
+        id: UserId,
+        request: TypedUpdateUserRequest<A, U>,
+    ) -> Result<TypedUser<A, U>>
+    where
+        A: Serialize + DeserializeOwned,
+        U: Serialize + DeserializeOwned,
+    {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/users/{}",
+            urlencoding::encode(id.as_str())
+        ))?;
+
+        self.client.patch(url, &request).await
+    }
+
+    /// Apply metadata patches to a user, sending only the changed top-level keys.
+    ///
+    /// Auth0 merges `app_metadata` and `user_metadata` only at the top level, so if either
+    /// patch contains nested path operations the user is fetched first and the affected
+    /// objects are sent in full with their existing siblings preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    /// * `app_metadata` - Optional patch for `app_metadata`.
+    /// * `user_metadata` - Optional patch for `user_metadata`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated user details.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Configuration` if both patches are absent or empty.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::{MetadataPatch, UserId};
+    /// let patch = MetadataPatch::new().set("plan", "pro").remove("trial_ends_at");
+    /// client.users().patch_metadata(UserId::new("auth0|123456"), Some(patch), None).await?;
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/manage-users/user-accounts/metadata/manage-metadata-api>
+    pub async fn patch_metadata(
+        &self,
+        id: UserId,
+        app_metadata: Option<MetadataPatch>,
+        user_metadata: Option<MetadataPatch>,
+    ) -> Result<User> {
+        let app_metadata = app_metadata.filter(|p| !p.is_empty());
+        let user_metadata = user_metadata.filter(|p| !p.is_empty());
+
+        if app_metadata.is_none() && user_metadata.is_none() {
+            return Err(Auth0Error::Configuration(
+                "metadata patch must contain at least one change".into(),
+            ));
+        }
+
+        let needs_base = app_metadata
+            .iter()
+            .chain(&user_metadata)
+            .any(|p| p.has_nested());
+        let current = if needs_base {
+            Some(self.get(id.clone()).await?)
+        } else {
+            None
+        };
+
+        let mut body = serde_json::Map::new();
+        if let Some(patch) = app_metadata {
+            let base = current.as_ref().and_then(|u| u.app_metadata.as_ref());
+            body.insert("app_metadata".into(), patch.to_body(base).into());
+        }
+        if let Some(patch) = user_metadata {
+            let base = current.as_ref().and_then(|u| u.user_metadata.as_ref());
+            body.insert("user_metadata".into(), patch.to_body(base).into());
+        }
+
+        let url = self.client.base_url().join(&format!(
+            "api/v2/users/{}",
+            urlencoding::encode(id.as_str())
+        ))?;
+
+        self.client.patch(url, &body).await
+    }
+
     /// Delete a user by their user ID.
     ///
     /// # Arguments
@@ -265,6 +442,354 @@ impl<'a> UsersApi<'a> {
         self.client.delete(url).await
     }
 
+    /// Link a secondary account to a primary user.
+    ///
+    /// The secondary account's identity is moved onto the primary user and the secondary
+    /// user is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary` - The user ID of the primary account.
+    /// * `request` - The secondary account, by provider and user ID or by ID token.
+    ///
+    /// # Returns
+    ///
+    /// Returns the primary user's identities after linking.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::{LinkIdentityRequest, UserId};
+    /// let request = LinkIdentityRequest::user("google-oauth2", "1234567890");
+    /// let identities = client
+    ///     .users()
+    ///     .link_identity(UserId::new("auth0|123456"), request)
+    ///     .await?;
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/post_identities>
+    pub async fn link_identity(
+        &self,
+        primary: UserId,
+        request: LinkIdentityRequest,
+    ) -> Result<Vec<Identity>> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/users/{}/identities",
+            urlencoding::encode(primary.as_str())
+        ))?;
+
+        self.client.post(url, &request).await
+    }
+
+    /// Unlink a secondary identity from a primary user.
+    ///
+    /// The unlinked identity becomes a separate user again.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary` - The user ID of the primary account.
+    /// * `provider` - The provider of the identity to unlink, e.g. `google-oauth2`.
+    /// * `secondary_user_id` - The identity's user ID at the provider, without the `provider|` prefix.
+    ///
+    /// # Returns
+    ///
+    /// Returns the primary user's remaining identities.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::UserId;
+    /// let identities = client
+    ///     .users()
+    ///     .unlink_identity(UserId::new("auth0|123456"), "google-oauth2", "1234567890")
+    ///     .await?;
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/delete_user_identity_by_user_id>
+    pub async fn unlink_identity(
+        &self,
+        primary: UserId,
+        provider: &str,
+        secondary_user_id: &str,
+    ) -> Result<Vec<Identity>> {
+        let url = self.client.base_url().join(&format!(
+            "api/v2/users/{}/identities/{}/{}",
+            urlencoding::encode(primary.as_str()),
+            urlencoding::encode(provider),
+            urlencoding::encode(secondary_user_id)
+        ))?;
+
+        self.client.delete_json(url).await
+    }
+
+    /// List a user's Guardian MFA enrollments.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the user's enrollments, including pending ones.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/get_enrollments>
+    pub async fn list_enrollments(&self, id: UserId) -> Result<Vec<Enrollment>> {
+        let url = self.user_url(&id, "/enrollments")?;
+        self.client.get(url).await
+    }
+
+    /// Invalidate all remembered browsers for MFA, so the user is prompted again.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/post_invalidate_remember_browser>
+    pub async fn invalidate_remember_browser(&self, id: UserId) -> Result<()> {
+        let url = self.user_url(&id, "/multifactor/actions/invalidate-remember-browser")?;
+        self.client
+            .post_no_content(url, &serde_json::json!({}))
+            .await
+    }
+
+    /// List the authentication methods enrolled by a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    /// * `params` - Optional pagination parameters.
+    ///
+    /// # Returns
+    ///
+    /// Returns the user's authentication methods.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::{AuthenticationMethod, UserId};
+    /// let methods = client
+    ///     .users()
+    ///     .list_authentication_methods(UserId::new("auth0|123456"), None)
+    ///     .await?;
+    /// for method in &methods {
+    ///     if let AuthenticationMethod::Phone { phone_number, .. } = method {
+    ///         println!("Phone: {:?}", phone_number);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/get_authentication_methods>
+    pub async fn list_authentication_methods(
+        &self,
+        id: UserId,
+        params: Option<PaginationParams>,
+    ) -> Result<Vec<AuthenticationMethod>> {
+        let mut url = self.user_url(&id, "/authentication-methods")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// Create an authentication method for a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    /// * `request` - The authentication method to enroll.
+    ///
+    /// # Returns
+    ///
+    /// Returns the created authentication method.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::{CreateAuthenticationMethodRequest, UserId};
+    /// let request = CreateAuthenticationMethodRequest::Email {
+    ///     email: "user@example.com".to_string(),
+    ///     name: None,
+    /// };
+    /// client
+    ///     .users()
+    ///     .create_authentication_method(UserId::new("auth0|123456"), request)
+    ///     .await?;
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/post_authentication_methods>
+    pub async fn create_authentication_method(
+        &self,
+        id: UserId,
+        request: CreateAuthenticationMethodRequest,
+    ) -> Result<AuthenticationMethod> {
+        let url = self.user_url(&id, "/authentication-methods")?;
+        self.client.post(url, &request).await
+    }
+
+    /// Delete a single authentication method from a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    /// * `method_id` - The authentication method ID.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/delete_authentication_methods_by_authentication_method_id>
+    pub async fn delete_authentication_method(&self, id: UserId, method_id: &str) -> Result<()> {
+        let url = self.user_url(
+            &id,
+            &format!("/authentication-methods/{}", urlencoding::encode(method_id)),
+        )?;
+        self.client.delete(url).await
+    }
+
+    /// Delete all authentication methods from a user, resetting their MFA.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/delete_authentication_methods>
+    pub async fn delete_authentication_methods(&self, id: UserId) -> Result<()> {
+        let url = self.user_url(&id, "/authentication-methods")?;
+        self.client.delete(url).await
+    }
+
+    /// Remove a user's current recovery code and generate a new one.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new recovery code.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2#!/Users/post_recovery_code_regeneration>
+    pub async fn regenerate_recovery_code(&self, id: UserId) -> Result<RecoveryCode> {
+        let url = self.user_url(&id, "/recovery-code-regeneration")?;
+        self.client.post(url, &serde_json::json!({})).await
+    }
+
+    /// List a user's sessions.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    /// * `params` - Optional checkpoint pagination parameters.
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of sessions and the checkpoint for the next page.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use auth0_mgmt_api::UserId;
+    /// let page = client.users().list_sessions(UserId::new("auth0|123456"), None).await?;
+    /// for session in &page.sessions {
+    ///     println!("{} expires {:?}", session.id, session.expires_at);
+    /// }
+    /// ```
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/users/get-sessions-for-user>
+    pub async fn list_sessions(
+        &self,
+        id: UserId,
+        params: Option<CheckpointParams>,
+    ) -> Result<SessionsPage> {
+        let mut url = self.user_url(&id, "/sessions")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// Delete all of a user's sessions, logging them out everywhere.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/users/delete-sessions-for-user>
+    pub async fn delete_sessions(&self, id: UserId) -> Result<()> {
+        let url = self.user_url(&id, "/sessions")?;
+        self.client.delete(url).await
+    }
+
+    /// List the refresh tokens issued to a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    /// * `params` - Optional checkpoint pagination parameters.
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of refresh tokens and the checkpoint for the next page.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/users/get-refresh-tokens-for-user>
+    pub async fn list_refresh_tokens(
+        &self,
+        id: UserId,
+        params: Option<CheckpointParams>,
+    ) -> Result<RefreshTokensPage> {
+        let mut url = self.user_url(&id, "/refresh-tokens")?;
+
+        if let Some(p) = params {
+            let query = serde_urlencoded::to_string(&p)
+                .map_err(|e| Auth0Error::Configuration(e.to_string()))?;
+            url.set_query(Some(&query));
+        }
+
+        self.client.get(url).await
+    }
+
+    /// Revoke every refresh token issued to a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The user's unique identifier.
+    ///
+    /// # Documentation
+    ///
+    /// <https://auth0.com/docs/api/management/v2/users/delete-refresh-tokens-for-user>
+    pub async fn delete_refresh_tokens(&self, id: UserId) -> Result<()> {
+        let url = self.user_url(&id, "/refresh-tokens")?;
+        self.client.delete(url).await
+    }
+
     /// Get users by email address.
     ///
     /// # Arguments
@@ -380,4 +905,12 @@ impl<'a> UsersApi<'a> {
 
         self.client.get(url).await
     }
+
+    fn user_url(&self, id: &UserId, suffix: &str) -> Result<url::Url> {
+        Ok(self.client.base_url().join(&format!(
+            "api/v2/users/{}{}",
+            urlencoding::encode(id.as_str()),
+            suffix
+        ))?)
+    }
 }
@@ -7,12 +7,50 @@ use url::Url;
 
 use crate::error::{Auth0ApiError, Auth0Error, Result};
 
+#[cfg(feature = "actions")]
+use crate::api::actions::ActionsApi;
+#[cfg(feature = "attack_protection")]
+use crate::api::attack_protection::AttackProtectionApi;
+#[cfg(feature = "branding")]
+use crate::api::branding::BrandingApi;
 #[cfg(feature = "clients")]
 use crate::api::clients::ClientsApi;
 #[cfg(feature = "connections")]
 use crate::api::connections::ConnectionsApi;
+#[cfg(feature = "custom_domains")]
+use crate::api::custom_domains::CustomDomainsApi;
+#[cfg(feature = "device_credentials")]
+use crate::api::device_credentials::DeviceCredentialsApi;
+#[cfg(feature = "emails")]
+use crate::api::email_provider::EmailProviderApi;
+#[cfg(feature = "emails")]
+use crate::api::email_templates::EmailTemplatesApi;
+#[cfg(feature = "grants")]
+use crate::api::grants::GrantsApi;
+#[cfg(feature = "guardian")]
+use crate::api::guardian::GuardianApi;
+#[cfg(feature = "hooks")]
+use crate::api::hooks::HooksApi;
+#[cfg(feature = "keys")]
+use crate::api::keys::KeysApi;
+#[cfg(feature = "log_streams")]
+use crate::api::log_streams::LogStreamsApi;
 #[cfg(feature = "logs")]
 use crate::api::logs::LogsApi;
+#[cfg(feature = "branding")]
+use crate::api::prompts::PromptsApi;
+#[cfg(feature = "users")]
+use crate::api::refresh_tokens::RefreshTokensApi;
+#[cfg(feature = "rules")]
+use crate::api::rules::RulesApi;
+#[cfg(feature = "rules")]
+use crate::api::rules_configs::RulesConfigsApi;
+#[cfg(feature = "users")]
+use crate::api::sessions::SessionsApi;
+#[cfg(feature = "tenants")]
+use crate::api::tenants::TenantSettingsApi;
+#[cfg(feature = "users")]
+use crate::api::user_blocks::UserBlocksApi;
 #[cfg(feature = "users")]
 use crate::api::users::UsersApi;
 
@@ -50,6 +88,68 @@ impl Default for RetryConfig {
     }
 }
 
+/// Polling behavior for helpers that wait on asynchronous Auth0 operations.
+///
+/// The delay between checks grows by `multiplier` up to `max_interval`, and polling stops
+/// with `Auth0Error::Timeout` once `timeout` has elapsed.
+#[derive(Clone, Debug)]
+pub struct PollConfig {
+    /// Delay before the second check.
+    pub initial_interval: std::time::Duration,
+    /// Maximum delay between checks.
+    pub max_interval: std::time::Duration,
+    /// Multiplier applied to the delay after each check.
+    pub multiplier: f64,
+    /// Total time to wait before giving up.
+    pub timeout: std::time::Duration,
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        Self {
+            initial_interval: std::time::Duration::from_secs(1),
+            max_interval: std::time::Duration::from_secs(10),
+            multiplier: 2.0,
+            timeout: std::time::Duration::from_secs(120),
+        }
+    }
+}
+
+impl PollConfig {
+    /// Call `check` until it yields a value, backing off between attempts.
+    ///
+    /// `what` describes the awaited condition in the timeout error.
+    #[cfg_attr(
+        not(any(feature = "actions", feature = "custom_domains")),
+        allow(dead_code)
+    )]
+    pub(crate) async fn poll_until<T, F, Fut>(&self, what: &str, mut check: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<Option<T>>>,
+    {
+        let deadline = std::time::Instant::now() + self.timeout;
+        let mut delay = self.initial_interval;
+
+        loop {
+            if let Some(value) = check().await? {
+                return Ok(value);
+            }
+
+            let now = std::time::Instant::now();
+            if now >= deadline {
+                return Err(Auth0Error::Timeout(format!(
+                    "{} after {:?}",
+                    what, self.timeout
+                )));
+            }
+
+            tokio::time::sleep(delay.min(deadline - now)).await;
+            delay = delay.mul_f64(self.multiplier).min(self.max_interval);
+        }
+    }
+}
+
 #[derive(Clone)]
 struct Credentials {
     client_id: String,
@@ -247,10 +347,107 @@ impl ManagementClient {
         self.handle_response(response).await
     }
 
+    #[cfg_attr(
+        not(any(feature = "rules", feature = "guardian", feature = "keys")),
+        allow(dead_code)
+    )]
+    pub(crate) async fn put<T: DeserializeOwned, B: Serialize>(
+        &self,
+        url: Url,
+        body: &B,
+    ) -> Result<T> {
+        let token = self.get_token().await?;
+        let response = self
+            .http
+            .put(url)
+            .bearer_auth(&token)
+            .json(body)
+            .send()
+            .await?;
+
+        self.handle_response(response).await
+    }
+
     pub(crate) async fn delete(&self, url: Url) -> Result<()> {
         let token = self.get_token().await?;
         let response = self.http.delete(url).bearer_auth(&token).send().await?;
 
+        self.handle_empty_response(response).await
+    }
+
+    #[cfg_attr(not(feature = "users"), allow(dead_code))]
+    pub(crate) async fn delete_json<T: DeserializeOwned>(&self, url: Url) -> Result<T> {
+        let token = self.get_token().await?;
+        let response = self.http.delete(url).bearer_auth(&token).send().await?;
+
+        self.handle_response(response).await
+    }
+
+    #[cfg_attr(not(feature = "connections"), allow(dead_code))]
+    pub(crate) async fn get_no_content(&self, url: Url) -> Result<()> {
+        let token = self.get_token().await?;
+        let response = self.http.get(url).bearer_auth(&token).send().await?;
+
+        self.handle_empty_response(response).await
+    }
+
+    #[cfg_attr(not(any(feature = "hooks", feature = "users")), allow(dead_code))]
+    pub(crate) async fn post_no_content<B: Serialize>(&self, url: Url, body: &B) -> Result<()> {
+        let token = self.get_token().await?;
+        let response = self
+            .http
+            .post(url)
+            .bearer_auth(&token)
+            .json(body)
+            .send()
+            .await?;
+
+        self.handle_empty_response(response).await
+    }
+
+    #[cfg_attr(not(any(feature = "connections", feature = "hooks")), allow(dead_code))]
+    pub(crate) async fn patch_no_content<B: Serialize>(&self, url: Url, body: &B) -> Result<()> {
+        let token = self.get_token().await?;
+        let response = self
+            .http
+            .patch(url)
+            .bearer_auth(&token)
+            .json(body)
+            .send()
+            .await?;
+
+        self.handle_empty_response(response).await
+    }
+
+    #[cfg_attr(not(any(feature = "branding", feature = "guardian")), allow(dead_code))]
+    pub(crate) async fn put_no_content<B: Serialize>(&self, url: Url, body: &B) -> Result<()> {
+        let token = self.get_token().await?;
+        let response = self
+            .http
+            .put(url)
+            .bearer_auth(&token)
+            .json(body)
+            .send()
+            .await?;
+
+        self.handle_empty_response(response).await
+    }
+
+    #[cfg_attr(not(feature = "hooks"), allow(dead_code))]
+    pub(crate) async fn delete_with_body<B: Serialize>(&self, url: Url, body: &B) -> Result<()> {
+        let token = self.get_token().await?;
+        let response = self
+            .http
+            .delete(url)
+            .bearer_auth(&token)
+            .json(body)
+            .send()
+            .await?;
+
+        self.handle_empty_response(response).await
+    }
+
+    async fn handle_empty_response(&self, response: reqwest::Response) -> Result<()> {
         if response.status().is_success() {
             Ok(())
         } else {
@@ -296,6 +493,31 @@ impl ManagementClient {
         UsersApi::new(self)
     }
 
+    #[cfg(feature = "users")]
+    pub fn user_blocks(&self) -> UserBlocksApi<'_> {
+        UserBlocksApi::new(self)
+    }
+
+    #[cfg(feature = "users")]
+    pub fn sessions(&self) -> SessionsApi<'_> {
+        SessionsApi::new(self)
+    }
+
+    #[cfg(feature = "users")]
+    pub fn refresh_tokens(&self) -> RefreshTokensApi<'_> {
+        RefreshTokensApi::new(self)
+    }
+
+    #[cfg(feature = "device_credentials")]
+    pub fn device_credentials(&self) -> DeviceCredentialsApi<'_> {
+        DeviceCredentialsApi::new(self)
+    }
+
+    #[cfg(feature = "grants")]
+    pub fn grants(&self) -> GrantsApi<'_> {
+        GrantsApi::new(self)
+    }
+
     #[cfg(feature = "clients")]
     pub fn clients(&self) -> ClientsApi<'_> {
         ClientsApi::new(self)
@@ -310,6 +532,76 @@ impl ManagementClient {
     pub fn logs(&self) -> LogsApi<'_> {
         LogsApi::new(self)
     }
+
+    #[cfg(feature = "actions")]
+    pub fn actions(&self) -> ActionsApi<'_> {
+        ActionsApi::new(self)
+    }
+
+    #[cfg(feature = "rules")]
+    pub fn rules(&self) -> RulesApi<'_> {
+        RulesApi::new(self)
+    }
+
+    #[cfg(feature = "rules")]
+    pub fn rules_configs(&self) -> RulesConfigsApi<'_> {
+        RulesConfigsApi::new(self)
+    }
+
+    #[cfg(feature = "log_streams")]
+    pub fn log_streams(&self) -> LogStreamsApi<'_> {
+        LogStreamsApi::new(self)
+    }
+
+    #[cfg(feature = "tenants")]
+    pub fn tenant_settings(&self) -> TenantSettingsApi<'_> {
+        TenantSettingsApi::new(self)
+    }
+
+    #[cfg(feature = "emails")]
+    pub fn email_provider(&self) -> EmailProviderApi<'_> {
+        EmailProviderApi::new(self)
+    }
+
+    #[cfg(feature = "emails")]
+    pub fn email_templates(&self) -> EmailTemplatesApi<'_> {
+        EmailTemplatesApi::new(self)
+    }
+
+    #[cfg(feature = "branding")]
+    pub fn branding(&self) -> BrandingApi<'_> {
+        BrandingApi::new(self)
+    }
+
+    #[cfg(feature = "branding")]
+    pub fn prompts(&self) -> PromptsApi<'_> {
+        PromptsApi::new(self)
+    }
+
+    #[cfg(feature = "custom_domains")]
+    pub fn custom_domains(&self) -> CustomDomainsApi<'_> {
+        CustomDomainsApi::new(self)
+    }
+
+    #[cfg(feature = "guardian")]
+    pub fn guardian(&self) -> GuardianApi<'_> {
+        GuardianApi::new(self)
+    }
+
+    #[cfg(feature = "attack_protection")]
+    pub fn attack_protection(&self) -> AttackProtectionApi<'_> {
+        AttackProtectionApi::new(self)
+    }
+
+    #[cfg(feature = "keys")]
+    pub fn keys(&self) -> KeysApi<'_> {
+        KeysApi::new(self)
+    }
+
+    #[cfg(feature = "hooks")]
+    pub fn hooks(&self) -> HooksApi<'_> {
+        HooksApi::new(self)
+    }
 }
 
 #[derive(Default, Clone)]
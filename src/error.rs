@@ -26,6 +26,18 @@ pub enum Auth0Error {
 
     #[error("Configuration error: {0}")]
     Configuration(String),
+
+    #[error("Concurrent modification: {0}")]
+    ConcurrentModification(String),
+
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    #[error("Operation failed: {0}")]
+    OperationFailed(String),
+
+    #[error("Invalid certificate: {0}")]
+    InvalidCertificate(String),
 }
 
 pub type Result<T> = std::result::Result<T, Auth0Error>;
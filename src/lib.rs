@@ -1,24 +1,84 @@
 pub mod api;
 pub mod client;
 pub mod error;
+#[cfg(feature = "log-receiver")]
+pub mod log_receiver;
 pub mod types;
 
-pub use client::{ManagementClient, ManagementClientBuilder, RetryConfig};
+pub use client::{ManagementClient, ManagementClientBuilder, PollConfig, RetryConfig};
 pub use error::{Auth0Error, Result};
 pub use types::{
-    AppType, ClientId, ConnectionId, ConnectionStrategy, GrantType, LogEventType,
-    OrganizationRequireBehavior, OrganizationUsage, Page, PerPage, SearchEngine, SortDirection,
-    SortSpec, TokenAuthMethod, UserId,
+    ActionId, AppType, CheckpointParams, ClientId, ConnectionId, ConnectionStrategy, DateRange,
+    GrantType, LogEventCategory, LogEventType, Metadata, MetadataPatch,
+    OrganizationRequireBehavior, OrganizationUsage, Page, Patch, PerPage, RuleId, SearchEngine,
+    SortDirection, SortSpec, Timestamp, TokenAuthMethod, UserId,
 };
 
 #[cfg(feature = "users")]
 pub use types::users::*;
 
+#[cfg(feature = "users")]
+pub use types::mfa::*;
+
+#[cfg(feature = "users")]
+pub use types::user_blocks::*;
+
+#[cfg(feature = "users")]
+pub use types::sessions::*;
+
+#[cfg(feature = "users")]
+pub use types::refresh_tokens::*;
+
+#[cfg(feature = "device_credentials")]
+pub use types::device_credentials::*;
+
+#[cfg(feature = "grants")]
+pub use types::grants::*;
+
 #[cfg(feature = "clients")]
 pub use types::clients::*;
 
 #[cfg(feature = "connections")]
 pub use types::connections::*;
 
+#[cfg(feature = "connections")]
+pub use types::connection_options::*;
+
 #[cfg(feature = "logs")]
 pub use types::logs::*;
+
+#[cfg(feature = "actions")]
+pub use types::actions::*;
+
+#[cfg(feature = "rules")]
+pub use types::rules::*;
+
+#[cfg(feature = "log_streams")]
+pub use types::log_streams::*;
+
+#[cfg(feature = "tenants")]
+pub use types::tenants::*;
+
+#[cfg(feature = "emails")]
+pub use types::emails::*;
+
+#[cfg(feature = "branding")]
+pub use types::branding::*;
+
+#[cfg(feature = "branding")]
+pub use types::prompts::*;
+
+#[cfg(feature = "custom_domains")]
+pub use types::custom_domains::*;
+
+#[cfg(feature = "guardian")]
+pub use types::guardian::*;
+
+#[cfg(feature = "attack_protection")]
+pub use types::attack_protection::*;
+
+#[cfg(feature = "keys")]
+pub use types::keys::*;
+
+#[cfg(feature = "hooks")]
+pub use types::hooks::*;
@@ -0,0 +1,219 @@
+//! Receiver for events pushed by Auth0 HTTP log streams.
+//!
+//! Auth0 delivers batches of log events to a webhook as a JSON array, as JSON lines, or
+//! as single JSON objects, depending on the stream's content format. Each event is either
+//! a bare log entry or wrapped as `{"log_id": ..., "data": {...}}`. [`LogStreamReceiver`]
+//! accepts all of these and yields the same [`LogEvent`] type returned by the Logs API.
+//!
+//! [`LogStreamReceiver::decode`] works with any web framework. For servers built on the
+//! `http` types, such as hyper and axum, [`LogStreamReceiver::receive`] reads the request
+//! directly and [`LogStreamReceiver::response`] builds the reply.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use auth0_mgmt_api::log_receiver::LogStreamReceiver;
+//!
+//! let receiver = LogStreamReceiver::with_authorization("Bearer my-shared-secret")
+//!     .with_max_body_size(2 * 1024 * 1024);
+//!
+//! // Inside a hyper `service_fn` or an axum handler taking `http::Request<Body>`:
+//! let result = receiver.receive(request).await;
+//! if let Ok(events) = &result {
+//!     for event in events {
+//!         println!("{} {}", event.event_type, event.log_id);
+//!     }
+//! }
+//! return Ok(LogStreamReceiver::response(&result));
+//! ```
+
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use subtle::ConstantTimeEq;
+
+use crate::error::{Auth0Error, Result};
+use crate::types::logs::LogEvent;
+
+/// Largest body, in bytes, that [`LogStreamReceiver::receive`] reads by default.
+pub const DEFAULT_MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
+
+/// Validates and decodes log stream deliveries.
+#[derive(Debug, Clone)]
+pub struct LogStreamReceiver {
+    authorization: Option<SecretString>,
+    max_body_size: usize,
+}
+
+impl Default for LogStreamReceiver {
+    fn default() -> Self {
+        Self {
+            authorization: None,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        }
+    }
+}
+
+impl LogStreamReceiver {
+    /// Create a receiver that accepts deliveries without checking the `Authorization` header.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a receiver that requires the `Authorization` header to equal `expected`.
+    ///
+    /// `expected` is the full header value configured as the stream's authorization token,
+    /// e.g. `Bearer my-shared-secret`.
+    pub fn with_authorization(expected: impl Into<SecretString>) -> Self {
+        Self {
+            authorization: Some(expected.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the largest body, in bytes, that [`receive`](Self::receive) reads.
+    ///
+    /// Defaults to [`DEFAULT_MAX_BODY_SIZE`]. Bodies passed to [`decode`](Self::decode) are
+    /// already in memory and are not checked.
+    pub fn with_max_body_size(mut self, limit: usize) -> Self {
+        self.max_body_size = limit;
+        self
+    }
+
+    /// Check the `Authorization` header of a delivery.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Authentication` if a token is configured and the header is
+    /// missing or does not match.
+    pub fn verify_authorization(&self, header: Option<&str>) -> Result<()> {
+        let Some(expected) = &self.authorization else {
+            return Ok(());
+        };
+
+        let matches = header.is_some_and(|value| {
+            bool::from(value.as_bytes().ct_eq(expected.expose_secret().as_bytes()))
+        });
+        if matches {
+            Ok(())
+        } else {
+            Err(Auth0Error::Authentication {
+                message: "log stream authorization header is missing or invalid".into(),
+            })
+        }
+    }
+
+    /// Validate the `Authorization` header and decode the body of a delivery.
+    ///
+    /// # Arguments
+    ///
+    /// * `authorization` - The request's `Authorization` header, if present.
+    /// * `body` - The raw request body.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Authentication` for a bad header and `Auth0Error::Json` for a
+    /// body that cannot be decoded.
+    pub fn decode(&self, authorization: Option<&str>, body: &[u8]) -> Result<Vec<LogEvent>> {
+        self.verify_authorization(authorization)?;
+        decode_body(body)
+    }
+
+    /// Read and decode a delivery from an `http::Request`.
+    ///
+    /// Works with hyper's `Incoming` body, axum's `Body`, or any other `http_body::Body`.
+    ///
+    /// # Errors
+    ///
+    /// As [`decode`](Self::decode), plus `Auth0Error::OperationFailed` if the body cannot
+    /// be read or is larger than the configured maximum.
+    pub async fn receive<B>(&self, request: http::Request<B>) -> Result<Vec<LogEvent>>
+    where
+        B: http_body::Body,
+        B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let authorization = request
+            .headers()
+            .get(http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned);
+        self.verify_authorization(authorization.as_deref())?;
+
+        let body = Limited::new(request.into_body(), self.max_body_size)
+            .collect()
+            .await
+            .map_err(|e| {
+                if e.is::<LengthLimitError>() {
+                    Auth0Error::OperationFailed(format!(
+                        "log stream body exceeds {} bytes",
+                        self.max_body_size
+                    ))
+                } else {
+                    Auth0Error::OperationFailed(format!("failed to read log stream body: {}", e))
+                }
+            })?
+            .to_bytes();
+        decode_body(&body)
+    }
+
+    /// Build the HTTP response for the result of [`receive`](Self::receive).
+    ///
+    /// Successful deliveries get `200 OK` so Auth0 does not retry them; authorization
+    /// failures get `401` and undecodable bodies `400`.
+    pub fn response(result: &Result<Vec<LogEvent>>) -> http::Response<String> {
+        let (status, body) = match result {
+            Ok(_) => (http::StatusCode::OK, String::new()),
+            Err(Auth0Error::Authentication { .. }) => {
+                (http::StatusCode::UNAUTHORIZED, "unauthorized".to_string())
+            }
+            Err(Auth0Error::Json(_)) => (http::StatusCode::BAD_REQUEST, "invalid body".to_string()),
+            Err(_) => (
+                http::StatusCode::INTERNAL_SERVER_ERROR,
+                "internal error".to_string(),
+            ),
+        };
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        response
+    }
+}
+
+/// Decode a log stream body without checking authorization.
+///
+/// Accepts a JSON array, JSON lines, or one or more concatenated JSON objects. Events may
+/// be bare log entries or wrapped in `{"log_id": ..., "data": {...}}`.
+pub fn decode_body(body: &[u8]) -> Result<Vec<LogEvent>> {
+    let trimmed = body.trim_ascii_start();
+
+    let entries: Vec<serde_json::Value> = if trimmed.starts_with(b"[") {
+        serde_json::from_slice(trimmed)?
+    } else {
+        serde_json::Deserializer::from_slice(trimmed)
+            .into_iter::<serde_json::Value>()
+            .collect::<std::result::Result<_, _>>()?
+    };
+
+    entries.into_iter().map(decode_entry).collect()
+}
+
+fn decode_entry(entry: serde_json::Value) -> Result<LogEvent> {
+    #[derive(Deserialize)]
+    struct Envelope {
+        log_id: Option<String>,
+        data: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let is_wrapped =
+        entry.get("data").is_some_and(serde_json::Value::is_object) && entry.get("type").is_none();
+    if !is_wrapped {
+        return Ok(serde_json::from_value(entry)?);
+    }
+
+    let Envelope { log_id, mut data } = serde_json::from_value(entry)?;
+    if let Some(log_id) = log_id {
+        data.entry("log_id")
+            .or_insert(serde_json::Value::String(log_id));
+    }
+    Ok(serde_json::from_value(serde_json::Value::Object(data))?)
+}
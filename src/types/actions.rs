@@ -0,0 +1,348 @@
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+use super::{ActionId, Patch, Timestamp, serialize_secret};
+
+/// Identifier of an Actions trigger (the flow an action runs in).
+///
+/// Triggers not modelled here are kept as [`ActionTriggerId::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ActionTriggerId {
+    PostLogin,
+    CredentialsExchange,
+    PreUserRegistration,
+    PostUserRegistration,
+    PostChangePassword,
+    SendPhoneMessage,
+    PasswordResetPostChallenge,
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl ActionTriggerId {
+    /// The trigger ID as used in API paths.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::PostLogin => "post-login",
+            Self::CredentialsExchange => "credentials-exchange",
+            Self::PreUserRegistration => "pre-user-registration",
+            Self::PostUserRegistration => "post-user-registration",
+            Self::PostChangePassword => "post-change-password",
+            Self::SendPhoneMessage => "send-phone-message",
+            Self::PasswordResetPostChallenge => "password-reset-post-challenge",
+            Self::Other(id) => id,
+        }
+    }
+}
+
+/// A trigger and version an action supports, or an entry in the trigger list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionTrigger {
+    pub id: ActionTriggerId,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub runtimes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_runtime: Option<String>,
+}
+
+impl ActionTrigger {
+    /// Reference a trigger at the given version, e.g. `post-login` `v3`.
+    pub fn new(id: ActionTriggerId, version: impl Into<String>) -> Self {
+        Self {
+            id,
+            version: version.into(),
+            status: None,
+            runtimes: Vec::new(),
+            default_runtime: None,
+        }
+    }
+}
+
+/// Build status of an action or action version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionBuildStatus {
+    Pending,
+    Building,
+    Packaged,
+    Built,
+    Retrying,
+    Failed,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// An npm dependency of an action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionDependency {
+    pub name: String,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_url: Option<String>,
+}
+
+impl ActionDependency {
+    /// Depend on an npm package at the given version.
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            registry_url: None,
+        }
+    }
+}
+
+/// A secret configured on an action. Values are never returned by the API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionSecret {
+    pub name: String,
+    pub updated_at: Option<Timestamp>,
+}
+
+/// A secret value to set on an action.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionSecretValue {
+    pub name: String,
+    #[serde(serialize_with = "serialize_secret")]
+    pub value: SecretString,
+}
+
+impl ActionSecretValue {
+    /// Create a secret with the given name and value.
+    pub fn new(name: impl Into<String>, value: impl Into<SecretString>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// A build or runtime error reported for an action version.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionError {
+    pub id: Option<String>,
+    pub msg: Option<String>,
+    pub url: Option<String>,
+}
+
+/// Represents an Auth0 Action.
+///
+/// See the [Auth0 Actions documentation](https://auth0.com/docs/customize/actions)
+/// for detailed information about actions.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Action {
+    pub id: ActionId,
+    pub name: String,
+    #[serde(default)]
+    pub supported_triggers: Vec<ActionTrigger>,
+    pub code: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<ActionDependency>,
+    pub runtime: Option<String>,
+    #[serde(default)]
+    pub secrets: Vec<ActionSecret>,
+    /// The currently deployed version, if any.
+    pub deployed_version: Option<ActionVersion>,
+    pub status: Option<ActionBuildStatus>,
+    /// Whether the draft matches the deployed version.
+    pub all_changes_deployed: Option<bool>,
+    pub built_at: Option<Timestamp>,
+    pub created_at: Option<Timestamp>,
+    pub updated_at: Option<Timestamp>,
+}
+
+/// An immutable, numbered version of an action created by deploying it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionVersion {
+    pub id: String,
+    pub action_id: Option<ActionId>,
+    pub number: Option<u32>,
+    pub code: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<ActionDependency>,
+    pub runtime: Option<String>,
+    #[serde(default)]
+    pub secrets: Vec<ActionSecret>,
+    #[serde(default)]
+    pub supported_triggers: Vec<ActionTrigger>,
+    /// Whether this is the version currently deployed.
+    #[serde(default)]
+    pub deployed: bool,
+    pub status: Option<ActionBuildStatus>,
+    #[serde(default)]
+    pub errors: Vec<ActionError>,
+    pub built_at: Option<Timestamp>,
+    pub created_at: Option<Timestamp>,
+    pub updated_at: Option<Timestamp>,
+}
+
+/// Request payload for creating an action.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateActionRequest {
+    pub name: String,
+    pub supported_triggers: Vec<ActionTrigger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<Vec<ActionDependency>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<Vec<ActionSecretValue>>,
+}
+
+impl CreateActionRequest {
+    /// Create an action for a single trigger, with the remaining fields unset.
+    pub fn new(name: impl Into<String>, trigger: ActionTrigger) -> Self {
+        Self {
+            name: name.into(),
+            supported_triggers: vec![trigger],
+            code: None,
+            runtime: None,
+            dependencies: None,
+            secrets: None,
+        }
+    }
+}
+
+/// Request payload for updating an action's draft.
+///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field. Changes take effect once the action is deployed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateActionRequest {
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub supported_triggers: Patch<Vec<ActionTrigger>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub code: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub runtime: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub dependencies: Patch<Vec<ActionDependency>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub secrets: Patch<Vec<ActionSecretValue>>,
+}
+
+/// Query parameters for listing actions.
+///
+/// See the [Auth0 Get Actions documentation](https://auth0.com/docs/api/management/v2/actions/get-actions)
+/// for detailed information about available filters.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListActionsParams {
+    #[serde(rename = "triggerId", skip_serializing_if = "Option::is_none")]
+    pub trigger_id: Option<ActionTriggerId>,
+    #[serde(rename = "actionName", skip_serializing_if = "Option::is_none")]
+    pub action_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+/// Paginated response for action list operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionsPage {
+    pub actions: Vec<Action>,
+    pub total: Option<u32>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+/// Paginated response for action version list operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionVersionsPage {
+    pub versions: Vec<ActionVersion>,
+    pub total: Option<u32>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+/// Summary of the action referenced by a trigger binding.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BoundAction {
+    pub id: ActionId,
+    pub name: String,
+    #[serde(default)]
+    pub supported_triggers: Vec<ActionTrigger>,
+}
+
+/// An action bound to a trigger, in execution order.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionBinding {
+    pub id: String,
+    pub trigger_id: Option<ActionTriggerId>,
+    pub display_name: Option<String>,
+    pub action: Option<BoundAction>,
+    pub created_at: Option<Timestamp>,
+    pub updated_at: Option<Timestamp>,
+}
+
+/// Paginated response for trigger binding list operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionBindingsPage {
+    pub bindings: Vec<ActionBinding>,
+    pub total: Option<u32>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+/// How a binding update refers to an action.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum BindingRef {
+    ActionId(ActionId),
+    ActionName(String),
+    BindingId(String),
+}
+
+/// One entry in a trigger's new binding list.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionBindingUpdate {
+    #[serde(rename = "ref")]
+    pub reference: BindingRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
+impl ActionBindingUpdate {
+    /// Bind the deployed version of the action with the given ID.
+    pub fn action(id: ActionId) -> Self {
+        Self {
+            reference: BindingRef::ActionId(id),
+            display_name: None,
+        }
+    }
+
+    /// Bind the deployed version of the action with the given name.
+    pub fn action_name(name: impl Into<String>) -> Self {
+        Self {
+            reference: BindingRef::ActionName(name.into()),
+            display_name: None,
+        }
+    }
+}
+
+/// Request payload for replacing a trigger's bindings.
+///
+/// The list is the complete, ordered set of bindings; omitted actions are unbound.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateBindingsRequest {
+    pub bindings: Vec<ActionBindingUpdate>,
+}
+
+/// Result of running an action against a test payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionTestResult {
+    /// The execution result, including any API calls the action made.
+    pub payload: serde_json::Value,
+}
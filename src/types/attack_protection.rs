@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+
+/// Response taken when an attack protection feature is triggered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Shield {
+    /// Block the login, signup or password change.
+    Block,
+    /// Email the affected user.
+    UserNotification,
+    /// Email tenant administrators.
+    AdminNotification,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// How brute-force protection counts failed attempts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BruteForceMode {
+    /// Count failures per account and IP address.
+    CountPerIdentifierAndIp,
+    /// Count failures per account, regardless of IP address.
+    CountPerIdentifier,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Brute-force protection settings.
+///
+/// Used both as the response and the update payload; fields left as `None` are not sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BruteForceProtection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+    /// IP addresses and CIDR ranges never blocked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowlist: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<BruteForceMode>,
+    /// Failed attempts before the shields apply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+}
+
+/// How often administrators are notified of breached password detections.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminNotificationFrequency {
+    Immediately,
+    Daily,
+    Weekly,
+    Monthly,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Breach data source used by breached password detection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreachedPasswordMethod {
+    Standard,
+    /// Includes recently breached credentials. Requires the Credential Guard add-on.
+    Enhanced,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Shields applied at one stage of breached password detection.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreachedPasswordStageSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+}
+
+/// Per-stage breached password detection settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreachedPasswordStages {
+    #[serde(
+        rename = "pre-user-registration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_user_registration: Option<BreachedPasswordStageSettings>,
+    #[serde(
+        rename = "pre-change-password",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_change_password: Option<BreachedPasswordStageSettings>,
+}
+
+/// Breached password detection settings.
+///
+/// Used both as the response and the update payload; fields left as `None` are not sent.
+/// `shields` apply at login; `stage` configures signup and password changes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreachedPasswordDetection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_notification_frequency: Option<Vec<AdminNotificationFrequency>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<BreachedPasswordMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<BreachedPasswordStages>,
+}
+
+/// Throttling limits for one stage of suspicious IP throttling.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThrottlingStageSettings {
+    /// Attempts allowed from one IP address before throttling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+    /// Milliseconds before one more attempt is allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<u64>,
+}
+
+/// Per-stage suspicious IP throttling settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThrottlingStages {
+    #[serde(rename = "pre-login", skip_serializing_if = "Option::is_none")]
+    pub pre_login: Option<ThrottlingStageSettings>,
+    #[serde(
+        rename = "pre-user-registration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_user_registration: Option<ThrottlingStageSettings>,
+}
+
+/// Suspicious IP throttling settings.
+///
+/// Used both as the response and the update payload; fields left as `None` are not sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspiciousIpThrottling {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+    /// IP addresses and CIDR ranges never throttled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowlist: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<ThrottlingStages>,
+}
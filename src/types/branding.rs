@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+use super::Patch;
+
+/// Brand colors used by the Universal Login pages.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BrandingColors {
+    /// Accent color, e.g. `#0059d6`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+    /// Either a color string or a gradient object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_background: Option<serde_json::Value>,
+}
+
+/// Custom font used by the Universal Login pages.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrandingFont {
+    /// HTTPS URL of the font file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Tenant-wide branding settings.
+///
+/// See the [Auth0 Branding documentation](https://auth0.com/docs/customize/login-pages/universal-login/customize-themes)
+/// for detailed information about branding.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Branding {
+    pub colors: Option<BrandingColors>,
+    pub favicon_url: Option<String>,
+    pub logo_url: Option<String>,
+    pub font: Option<BrandingFont>,
+}
+
+/// Request payload for updating branding settings.
+///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateBrandingRequest {
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub colors: Patch<BrandingColors>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub favicon_url: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub logo_url: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub font: Patch<BrandingFont>,
+}
+
+/// Style sections of a Universal Login theme.
+///
+/// Each section is a flat map of style properties, e.g. `colors.primary_button` or
+/// `borders.button_border_radius`. Auth0 requires every section when a theme is created
+/// or updated, so the usual workflow is to read a theme, change it, and write it back.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThemeSettings {
+    #[serde(default)]
+    pub borders: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    pub colors: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    pub fonts: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    pub page_background: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    pub widget: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Represents a Universal Login theme.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BrandingTheme {
+    #[serde(rename = "themeId")]
+    pub theme_id: String,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    #[serde(flatten)]
+    pub settings: ThemeSettings,
+}
+
+/// Request payload for creating or updating a Universal Login theme.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BrandingThemeRequest {
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(flatten)]
+    pub settings: ThemeSettings,
+}
+
+impl From<BrandingTheme> for BrandingThemeRequest {
+    fn from(theme: BrandingTheme) -> Self {
+        Self {
+            display_name: theme.display_name,
+            settings: theme.settings,
+        }
+    }
+}
+
+/// Custom page template for the Universal Login.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UniversalLoginTemplate {
+    /// Liquid template HTML. Must contain `{%- auth0:head -%}` and `{%- auth0:widget -%}`.
+    pub body: String,
+}
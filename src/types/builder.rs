@@ -0,0 +1,205 @@
+//! Shared pieces for the fluent request builders.
+//!
+//! Required fields are tracked with the [`Missing`] and [`Provided`] typestate markers, so
+//! `build()` is only callable once every mandatory field has been supplied. Everything
+//! else is checked at `build()` time and reported as `Auth0Error::Configuration`.
+
+use serde::Serialize;
+
+use crate::error::{Auth0Error, Result};
+
+/// Typestate marker for a required builder field that has not been set yet.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Missing;
+
+/// Typestate marker for a required builder field that has been set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Provided;
+
+/// Generate setters that wrap the value in `Some` on `self.request`.
+macro_rules! option_setters {
+    ($($(#[$doc:meta])* $field:ident: $ty:ty),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $field(mut self, value: impl Into<$ty>) -> Self {
+                self.request.$field = Some(value.into());
+                self
+            }
+        )*
+    };
+}
+
+/// Generate setters for `Option<Vec<String>>` fields on `self.request`.
+#[cfg(any(feature = "clients", feature = "connections"))]
+macro_rules! list_setters {
+    ($($(#[$doc:meta])* $field:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $field(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+                self.request.$field = Some(values.into_iter().map(Into::into).collect());
+                self
+            }
+        )*
+    };
+}
+
+/// Generate a setter for each `Patch<T>` field on `self.request`.
+///
+/// Fields written as `field / clear_field: T` also get a clearing method; leave it out for
+/// fields Auth0 does not accept as `null`.
+macro_rules! patch_setters {
+    ($($(#[$doc:meta])* $field:ident $(/ $clear:ident)?: $ty:ty),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $field(mut self, value: impl Into<$ty>) -> Self {
+                self.request.$field = $crate::types::Patch::Value(value.into());
+                self
+            }
+
+            $(
+                #[doc = concat!("Clear `", stringify!($field), "` (sent as `null`).")]
+                pub fn $clear(mut self) -> Self {
+                    self.request.$field = $crate::types::Patch::Null;
+                    self
+                }
+            )?
+        )*
+    };
+}
+
+/// Generate setters for `Patch<Vec<String>>` fields on `self.request`.
+#[cfg(any(feature = "clients", feature = "connections"))]
+macro_rules! patch_list_setters {
+    ($($(#[$doc:meta])* $field:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $field(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+                self.request.$field =
+                    $crate::types::Patch::Value(values.into_iter().map(Into::into).collect());
+                self
+            }
+        )*
+    };
+}
+
+pub(crate) use {option_setters, patch_setters};
+
+#[cfg(any(feature = "clients", feature = "connections"))]
+pub(crate) use {list_setters, patch_list_setters};
+
+/// Ensure a required string field is not blank.
+#[cfg(any(feature = "users", feature = "clients"))]
+pub(crate) fn require_non_empty(field: &str, value: &str) -> Result<()> {
+    if value.trim().is_empty() {
+        return Err(Auth0Error::Configuration(format!(
+            "{} must not be empty",
+            field
+        )));
+    }
+    Ok(())
+}
+
+/// Ensure every entry parses as an absolute URL.
+///
+/// Auth0 wildcards such as `https://*.example.com/callback` are accepted.
+#[cfg(feature = "clients")]
+pub(crate) fn validate_urls<'a>(
+    field: &str,
+    urls: impl IntoIterator<Item = &'a String>,
+) -> Result<()> {
+    for u in urls {
+        if u.chars().any(char::is_whitespace) {
+            return Err(Auth0Error::Configuration(format!(
+                "{} contains an invalid URL '{}': whitespace is not allowed",
+                field, u
+            )));
+        }
+        url::Url::parse(u).map_err(|e| {
+            Auth0Error::Configuration(format!("{} contains an invalid URL '{}': {}", field, u, e))
+        })?;
+    }
+    Ok(())
+}
+
+/// Ensure a connection name follows Auth0's naming rules.
+///
+/// Names are 1-128 characters of ASCII letters, digits and single hyphens, and must
+/// start and end with a letter or digit.
+#[cfg(feature = "connections")]
+pub(crate) fn validate_connection_name(name: &str) -> Result<()> {
+    let invalid = |reason: &str| {
+        Err(Auth0Error::Configuration(format!(
+            "invalid connection name '{}': {}",
+            name, reason
+        )))
+    };
+
+    if name.is_empty() || name.len() > 128 {
+        return invalid("must be between 1 and 128 characters");
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return invalid("only letters, digits and hyphens are allowed");
+    }
+    if name.starts_with('-') || name.ends_with('-') {
+        return invalid("must start and end with a letter or digit");
+    }
+    if name.contains("--") {
+        return invalid("consecutive hyphens are not allowed");
+    }
+    Ok(())
+}
+
+/// Reject update requests that would serialize to an empty JSON object.
+pub(crate) fn require_changes<T: Serialize>(what: &str, request: &T) -> Result<()> {
+    match serde_json::to_value(request)? {
+        serde_json::Value::Object(map) if map.is_empty() => Err(Auth0Error::Configuration(
+            format!("{} must change at least one field", what),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "connections")]
+    fn test_validate_connection_name() {
+        assert!(validate_connection_name("Username-Password-Authentication").is_ok());
+        assert!(validate_connection_name("google-oauth2").is_ok());
+        assert!(validate_connection_name("").is_err());
+        assert!(validate_connection_name("-leading").is_err());
+        assert!(validate_connection_name("trailing-").is_err());
+        assert!(validate_connection_name("double--hyphen").is_err());
+        assert!(validate_connection_name("has space").is_err());
+        assert!(validate_connection_name(&"a".repeat(129)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "clients")]
+    fn test_validate_urls() {
+        let ok = vec![
+            "https://example.com/callback".to_string(),
+            "https://*.example.com/callback".to_string(),
+            "myapp://callback".to_string(),
+        ];
+        assert!(validate_urls("callbacks", &ok).is_ok());
+
+        let bad = vec!["not a url".to_string()];
+        assert!(validate_urls("callbacks", &bad).is_err());
+        let relative = vec!["/callback".to_string()];
+        assert!(validate_urls("callbacks", &relative).is_err());
+    }
+
+    #[test]
+    fn test_require_changes() {
+        #[derive(Serialize)]
+        struct Empty {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            a: Option<u8>,
+        }
+        assert!(require_changes("update", &Empty { a: None }).is_err());
+        assert!(require_changes("update", &Empty { a: Some(1) }).is_ok());
+    }
+}
@@ -1,5 +1,14 @@
+use std::marker::PhantomData;
+
 use serde::{Deserialize, Serialize};
 
+use crate::error::Result;
+
+use super::Patch;
+use super::builder::{
+    Missing, Provided, list_setters, option_setters, patch_list_setters, patch_setters,
+    require_changes, require_non_empty, validate_urls,
+};
 use super::enums::{
     AppType, GrantType, OrganizationRequireBehavior, OrganizationUsage, TokenAuthMethod,
 };
@@ -109,50 +118,282 @@ pub struct CreateClientRequest {
 
 /// Request payload for updating an application.
 ///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field.
+///
 /// See the [Auth0 Update Application documentation](https://auth0.com/docs/api/management/v2#!/Clients/patch_clients_by_id)
 /// for detailed information about application updates.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct UpdateClientRequest {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub logo_uri: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub callbacks: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_origins: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub web_origins: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_aliases: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_clients: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_logout_urls: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub grant_types: Option<Vec<GrantType>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_endpoint_auth_method: Option<TokenAuthMethod>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub app_type: Option<AppType>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub oidc_conformant: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sso: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sso_disabled: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cross_origin_auth: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cross_origin_loc: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_login_page_on: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_login_page: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub initiate_login_uri: Option<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub description: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub logo_uri: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub callbacks: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub allowed_origins: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub web_origins: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub client_aliases: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub allowed_clients: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub allowed_logout_urls: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub grant_types: Patch<Vec<GrantType>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub token_endpoint_auth_method: Patch<TokenAuthMethod>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub app_type: Patch<AppType>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub oidc_conformant: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub sso: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub sso_disabled: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub cross_origin_auth: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub cross_origin_loc: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub custom_login_page_on: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub custom_login_page: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub initiate_login_uri: Patch<String>,
+}
+
+impl CreateClientRequest {
+    /// Start building a [`CreateClientRequest`].
+    ///
+    /// `name` is required before `build()` becomes available.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let request = CreateClientRequest::builder()
+    ///     .name("My Web App")
+    ///     .app_type(AppType::RegularWeb)
+    ///     .callbacks(["https://example.com/callback"])
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> CreateClientRequestBuilder<Missing> {
+        CreateClientRequestBuilder {
+            request: CreateClientRequest::default(),
+            _state: PhantomData,
+        }
+    }
+}
+
+/// Fluent builder for [`CreateClientRequest`].
+///
+/// The `N` parameter tracks whether `name` has been set.
+#[derive(Debug, Clone)]
+pub struct CreateClientRequestBuilder<N> {
+    request: CreateClientRequest,
+    _state: PhantomData<N>,
+}
+
+impl CreateClientRequestBuilder<Missing> {
+    /// Set the application name.
+    pub fn name(mut self, name: impl Into<String>) -> CreateClientRequestBuilder<Provided> {
+        self.request.name = name.into();
+        CreateClientRequestBuilder {
+            request: self.request,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<N> CreateClientRequestBuilder<N> {
+    option_setters! {
+        /// Set the application description.
+        description: String,
+        /// Set the URL of the application logo.
+        logo_uri: String,
+        /// Set the token endpoint authentication method.
+        token_endpoint_auth_method: TokenAuthMethod,
+        /// Set the application type.
+        app_type: AppType,
+        /// Set whether the application is OIDC conformant.
+        oidc_conformant: bool,
+        /// Set whether Auth0 handles single sign-on.
+        sso: bool,
+        /// Set whether cross-origin authentication is allowed.
+        cross_origin_auth: bool,
+        /// Set the cross-origin verification fallback URL.
+        cross_origin_loc: String,
+        /// Set whether a custom login page is used.
+        custom_login_page_on: bool,
+        /// Set the custom login page HTML.
+        custom_login_page: String,
+        /// Set the URI used to initiate login.
+        initiate_login_uri: String,
+        /// Set how the application can be used with organizations.
+        organization_usage: OrganizationUsage,
+        /// Set how the organization prompt behaves.
+        organization_require_behavior: OrganizationRequireBehavior,
+    }
+
+    list_setters! {
+        /// Set the allowed callback URLs.
+        callbacks,
+        /// Set the allowed CORS origins.
+        allowed_origins,
+        /// Set the allowed web origins.
+        web_origins,
+        /// Set the client aliases.
+        client_aliases,
+        /// Set the clients allowed to request delegation tokens.
+        allowed_clients,
+        /// Set the allowed logout URLs.
+        allowed_logout_urls,
+    }
+
+    /// Set the allowed grant types.
+    pub fn grant_types(mut self, grant_types: impl IntoIterator<Item = GrantType>) -> Self {
+        self.request.grant_types = Some(grant_types.into_iter().collect());
+        self
+    }
+}
+
+impl CreateClientRequestBuilder<Provided> {
+    /// Validate and build the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Configuration` if `name` is blank or any callback, origin,
+    /// logout or login URL is not a valid absolute URL.
+    pub fn build(self) -> Result<CreateClientRequest> {
+        let request = self.request;
+        require_non_empty("name", &request.name)?;
+        validate_client_urls(
+            request.callbacks.iter().flatten(),
+            request.allowed_origins.iter().flatten(),
+            request.web_origins.iter().flatten(),
+            request.allowed_logout_urls.iter().flatten(),
+            request.initiate_login_uri.iter(),
+        )?;
+        Ok(request)
+    }
+}
+
+impl UpdateClientRequest {
+    /// Start building an [`UpdateClientRequest`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let request = UpdateClientRequest::builder()
+    ///     .callbacks(["https://example.com/callback"])
+    ///     .clear_description()
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> UpdateClientRequestBuilder {
+        UpdateClientRequestBuilder {
+            request: UpdateClientRequest::default(),
+        }
+    }
+}
+
+/// Fluent builder for [`UpdateClientRequest`].
+///
+/// Each scalar setter has a matching `clear_*` method that sends `null` for the field.
+#[derive(Debug, Clone)]
+pub struct UpdateClientRequestBuilder {
+    request: UpdateClientRequest,
+}
+
+impl UpdateClientRequestBuilder {
+    patch_setters! {
+        /// Set the application name.
+        name: String,
+        /// Set the application description.
+        description / clear_description: String,
+        /// Set the URL of the application logo.
+        logo_uri / clear_logo_uri: String,
+        /// Set the token endpoint authentication method.
+        token_endpoint_auth_method / clear_token_endpoint_auth_method: TokenAuthMethod,
+        /// Set the application type.
+        app_type: AppType,
+        /// Set whether the application is OIDC conformant.
+        oidc_conformant: bool,
+        /// Set whether Auth0 handles single sign-on.
+        sso: bool,
+        /// Set whether single sign-on is disabled.
+        sso_disabled: bool,
+        /// Set whether cross-origin authentication is allowed.
+        cross_origin_auth: bool,
+        /// Set the cross-origin verification fallback URL.
+        cross_origin_loc / clear_cross_origin_loc: String,
+        /// Set whether a custom login page is used.
+        custom_login_page_on: bool,
+        /// Set the custom login page HTML.
+        custom_login_page / clear_custom_login_page: String,
+        /// Set the URI used to initiate login.
+        initiate_login_uri / clear_initiate_login_uri: String,
+    }
+
+    patch_list_setters! {
+        /// Set the allowed callback URLs.
+        callbacks,
+        /// Set the allowed CORS origins.
+        allowed_origins,
+        /// Set the allowed web origins.
+        web_origins,
+        /// Set the client aliases.
+        client_aliases,
+        /// Set the clients allowed to request delegation tokens.
+        allowed_clients,
+        /// Set the allowed logout URLs.
+        allowed_logout_urls,
+    }
+
+    /// Set the allowed grant types.
+    pub fn grant_types(mut self, grant_types: impl IntoIterator<Item = GrantType>) -> Self {
+        self.request.grant_types = Patch::Value(grant_types.into_iter().collect());
+        self
+    }
+
+    /// Validate and build the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Configuration` if no field is changed, `name` is set to a blank
+    /// string, or any callback, origin, logout or login URL is not a valid absolute URL.
+    pub fn build(self) -> Result<UpdateClientRequest> {
+        let request = self.request;
+        require_changes("client update", &request)?;
+        if let Patch::Value(name) = &request.name {
+            require_non_empty("name", name)?;
+        }
+        validate_client_urls(
+            request.callbacks.as_value().into_iter().flatten(),
+            request.allowed_origins.as_value().into_iter().flatten(),
+            request.web_origins.as_value().into_iter().flatten(),
+            request.allowed_logout_urls.as_value().into_iter().flatten(),
+            request.initiate_login_uri.as_value(),
+        )?;
+        Ok(request)
+    }
+}
+
+fn validate_client_urls<'a>(
+    callbacks: impl IntoIterator<Item = &'a String>,
+    allowed_origins: impl IntoIterator<Item = &'a String>,
+    web_origins: impl IntoIterator<Item = &'a String>,
+    allowed_logout_urls: impl IntoIterator<Item = &'a String>,
+    initiate_login_uri: impl IntoIterator<Item = &'a String>,
+) -> Result<()> {
+    validate_urls("callbacks", callbacks)?;
+    validate_urls("allowed_origins", allowed_origins)?;
+    validate_urls("web_origins", web_origins)?;
+    validate_urls("allowed_logout_urls", allowed_logout_urls)?;
+    validate_urls("initiate_login_uri", initiate_login_uri)
 }
 
 /// Query parameters for listing applications.
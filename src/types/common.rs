@@ -1,6 +1,9 @@
 use std::ops::{Deref, DerefMut};
 
-use serde::{Deserialize, Serialize};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize, Serializer, de::DeserializeOwned};
+
+use crate::error::{Auth0Error, Result};
 
 /// Common pagination parameters for list operations.
 ///
@@ -13,6 +16,72 @@ pub struct PaginationParams {
     pub per_page: Option<u32>,
 }
 
+/// Checkpoint pagination parameters for list operations that return a `next` checkpoint.
+///
+/// Pass the `next` value of one page as `from` to fetch the following page.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CheckpointParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take: Option<u32>,
+}
+
+/// Timestamp returned by the Management API.
+///
+/// With the `chrono` feature enabled this is a `chrono::DateTime<Utc>` parsed from the
+/// ISO-8601 strings Auth0 returns. Without it, the raw string is kept as-is.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// Timestamp returned by the Management API.
+///
+/// With the `chrono` feature enabled this is a `chrono::DateTime<Utc>` parsed from the
+/// ISO-8601 strings Auth0 returns. Without it, the raw string is kept as-is.
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// Format a timestamp the way Auth0 expects it in search queries.
+#[cfg(feature = "chrono")]
+pub(crate) fn format_timestamp(ts: &Timestamp) -> String {
+    ts.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
+/// Format a timestamp the way Auth0 expects it in search queries.
+#[cfg(not(feature = "chrono"))]
+pub(crate) fn format_timestamp(ts: &Timestamp) -> String {
+    ts.clone()
+}
+
+/// Serialize a secret as a plain string in request bodies.
+#[cfg_attr(not(any(feature = "users", feature = "actions")), allow(dead_code))]
+pub(crate) fn serialize_secret<S: Serializer>(
+    secret: &SecretString,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(secret.expose_secret())
+}
+
+/// Serialize an optional secret as a plain string in request bodies.
+#[cfg_attr(
+    not(any(
+        feature = "connections",
+        feature = "log_streams",
+        feature = "emails",
+        feature = "guardian"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn serialize_optional_secret<S: Serializer>(
+    secret: &Option<SecretString>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match secret {
+        Some(secret) => serializer.serialize_some(secret.expose_secret()),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// User metadata as a JSON object.
 ///
 /// Metadata is arbitrary JSON data associated with users. Auth0 supports both app_metadata
@@ -29,6 +98,38 @@ impl Default for Metadata {
     }
 }
 
+impl Metadata {
+    /// Deserialize the metadata into a typed struct.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize)]
+    /// struct AppMeta { plan: String }
+    ///
+    /// let meta: AppMeta = metadata.to_typed()?;
+    /// ```
+    pub fn to_typed<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_value(serde_json::Value::Object(
+            self.0.clone(),
+        ))?)
+    }
+
+    /// Build metadata from any value that serializes to a JSON object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Configuration` if the value does not serialize to an object.
+    pub fn from_typed<T: Serialize>(value: &T) -> Result<Self> {
+        match serde_json::to_value(value)? {
+            serde_json::Value::Object(map) => Ok(Self(map)),
+            _ => Err(Auth0Error::Configuration(
+                "metadata must serialize to a JSON object".into(),
+            )),
+        }
+    }
+}
+
 impl Deref for Metadata {
     type Target = serde_json::Map<String, serde_json::Value>;
 
@@ -42,3 +143,237 @@ impl DerefMut for Metadata {
         &mut self.0
     }
 }
+
+/// A single operation recorded by [`MetadataPatch`].
+#[derive(Debug, Clone, PartialEq)]
+enum MetadataOp {
+    Set(Vec<String>, serde_json::Value),
+    Remove(Vec<String>),
+}
+
+/// A delta to apply to `app_metadata` or `user_metadata`.
+///
+/// Auth0 merges metadata on PATCH, but only at the top level: a key set to `null` is
+/// deleted, and a nested object replaces the stored object wholesale. `MetadataPatch`
+/// records the intended changes and renders the minimal body that produces them.
+///
+/// Nested path operations need the current value of their top-level key so that
+/// sibling fields are preserved, so the patch is not `Serialize`: render the body with
+/// [`MetadataPatch::to_body`], passing the stored metadata as the base.
+///
+/// # Examples
+///
+/// ```ignore
+/// let patch = MetadataPatch::new()
+///     .set("plan", "pro")
+///     .remove("trial_ends_at")
+///     .set_path(["preferences", "theme"], "dark");
+/// client.users().patch_metadata(id, Some(patch), None).await?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetadataPatch {
+    ops: Vec<MetadataOp>,
+}
+
+impl MetadataPatch {
+    /// Create an empty patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a top-level key.
+    pub fn set(self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.set_path([key], value)
+    }
+
+    /// Remove a top-level key (sent as `null`).
+    pub fn remove(self, key: impl Into<String>) -> Self {
+        self.remove_path([key])
+    }
+
+    /// Set a value at a nested path, creating intermediate objects as needed.
+    pub fn set_path<I, S>(mut self, path: I, value: impl Into<serde_json::Value>) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let path: Vec<String> = path.into_iter().map(Into::into).collect();
+        if !path.is_empty() {
+            self.ops.push(MetadataOp::Set(path, value.into()));
+        }
+        self
+    }
+
+    /// Remove the value at a nested path.
+    pub fn remove_path<I, S>(mut self, path: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let path: Vec<String> = path.into_iter().map(Into::into).collect();
+        if !path.is_empty() {
+            self.ops.push(MetadataOp::Remove(path));
+        }
+        self
+    }
+
+    /// Compute the patch that turns `old` into `new`.
+    ///
+    /// Changes are expressed per top-level key, matching Auth0's merge semantics.
+    pub fn diff(old: &Metadata, new: &Metadata) -> Self {
+        let mut patch = Self::new();
+        for (key, value) in new.iter() {
+            if old.get(key) != Some(value) {
+                patch = patch.set(key.clone(), value.clone());
+            }
+        }
+        for key in old.keys() {
+            if !new.contains_key(key) {
+                patch = patch.remove(key.clone());
+            }
+        }
+        patch
+    }
+
+    /// Whether the patch contains no operations.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Whether any operation targets a nested path.
+    pub fn has_nested(&self) -> bool {
+        self.ops.iter().any(|op| match op {
+            MetadataOp::Set(path, _) | MetadataOp::Remove(path) => path.len() > 1,
+        })
+    }
+
+    /// Apply the patch locally, with the same result Auth0 produces server-side.
+    pub fn apply(&self, metadata: &mut Metadata) {
+        let body = self.to_body(Some(metadata));
+        for (key, value) in body {
+            if value.is_null() {
+                metadata.remove(&key);
+            } else {
+                metadata.insert(key, value);
+            }
+        }
+    }
+
+    /// Render the PATCH body containing only the touched top-level keys.
+    ///
+    /// Top-level removals are emitted as `null`. Keys touched by nested operations are
+    /// emitted as the full object, starting from its value in `base` when provided;
+    /// without a base, the object is built from the patch alone and will replace
+    /// whatever Auth0 currently stores under that key.
+    pub fn to_body(&self, base: Option<&Metadata>) -> serde_json::Map<String, serde_json::Value> {
+        let mut body = serde_json::Map::new();
+
+        for op in &self.ops {
+            let (path, value) = match op {
+                MetadataOp::Set(path, value) => (path, Some(value)),
+                MetadataOp::Remove(path) => (path, None),
+            };
+            let Some((key, rest)) = path.split_first() else {
+                continue;
+            };
+
+            if rest.is_empty() {
+                body.insert(
+                    key.clone(),
+                    value.cloned().unwrap_or(serde_json::Value::Null),
+                );
+                continue;
+            }
+
+            let entry = body.entry(key.clone()).or_insert_with(|| {
+                base.and_then(|b| b.get(key))
+                    .filter(|v| v.is_object())
+                    .cloned()
+                    .unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new()))
+            });
+            if !entry.is_object() {
+                *entry = serde_json::Value::Object(serde_json::Map::new());
+            }
+
+            let mut target = entry;
+            for segment in &rest[..rest.len() - 1] {
+                let map = target.as_object_mut().expect("target is always an object");
+                let next = map
+                    .entry(segment.clone())
+                    .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+                if !next.is_object() {
+                    *next = serde_json::Value::Object(serde_json::Map::new());
+                }
+                target = next;
+            }
+
+            let map = target.as_object_mut().expect("target is always an object");
+            let leaf = &rest[rest.len() - 1];
+            match value {
+                Some(v) => {
+                    map.insert(leaf.clone(), v.clone());
+                }
+                None => {
+                    map.remove(leaf);
+                }
+            }
+        }
+
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn metadata(value: serde_json::Value) -> Metadata {
+        Metadata::from_typed(&value).unwrap()
+    }
+
+    #[test]
+    fn test_metadata_patch_set_and_remove() {
+        let patch = MetadataPatch::new().set("plan", "pro").remove("trial");
+        assert_eq!(
+            serde_json::Value::Object(patch.to_body(None)),
+            json!({ "plan": "pro", "trial": null })
+        );
+    }
+
+    #[test]
+    fn test_metadata_patch_nested_preserves_siblings() {
+        let base = metadata(json!({ "prefs": { "theme": "light", "lang": "en" }, "plan": "free" }));
+        let patch = MetadataPatch::new()
+            .set_path(["prefs", "theme"], "dark")
+            .remove_path(["prefs", "lang"])
+            .set_path(["prefs", "beta", "enabled"], true);
+
+        assert_eq!(
+            serde_json::Value::Object(patch.to_body(Some(&base))),
+            json!({ "prefs": { "theme": "dark", "beta": { "enabled": true } } })
+        );
+    }
+
+    #[test]
+    fn test_metadata_patch_diff() {
+        let old = metadata(json!({ "a": 1, "b": { "c": 2 }, "gone": true }));
+        let new = metadata(json!({ "a": 1, "b": { "c": 3 }, "added": "x" }));
+
+        let patch = MetadataPatch::diff(&old, &new);
+        assert_eq!(
+            serde_json::Value::Object(patch.to_body(None)),
+            json!({ "b": { "c": 3 }, "added": "x", "gone": null })
+        );
+
+        let mut applied = old.clone();
+        patch.apply(&mut applied);
+        assert_eq!(applied.0, new.0);
+    }
+
+    #[test]
+    fn test_metadata_patch_empty() {
+        assert!(MetadataPatch::new().is_empty());
+        assert!(MetadataPatch::diff(&Metadata::default(), &Metadata::default()).is_empty());
+    }
+}
@@ -0,0 +1,330 @@
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+use super::enums::ConnectionStrategy;
+use super::serialize_optional_secret;
+
+/// Password strength policy for database connections.
+///
+/// See the [Auth0 Password Strength documentation](https://auth0.com/docs/authenticate/database-connections/password-strength)
+/// for the requirements of each level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PasswordPolicy {
+    /// At least 1 character
+    None,
+    /// At least 6 characters
+    Low,
+    /// At least 8 characters with lowercase, uppercase and numbers
+    Fair,
+    /// At least 8 characters with three of four character types
+    Good,
+    /// At least 10 characters, three of four character types, no repeated characters
+    Excellent,
+}
+
+/// Minimum password length requirement.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasswordComplexityOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+}
+
+/// Prevents reuse of recent passwords.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasswordHistory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+}
+
+/// Prevents passwords that contain personal data such as the user's name or email.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasswordNoPersonalInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable: Option<bool>,
+}
+
+/// Prevents passwords found in the default or a custom dictionary.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasswordDictionary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dictionary: Option<Vec<String>>,
+}
+
+/// Multi-factor authentication settings for a database connection.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionMfa {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_enroll_settings: Option<bool>,
+}
+
+/// Options for `auth0` (database) connections.
+///
+/// Unknown fields are kept in `extra` so that a read-modify-write round trip does not
+/// drop settings this crate does not model.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatabaseConnectionOptions {
+    #[serde(rename = "passwordPolicy", skip_serializing_if = "Option::is_none")]
+    pub password_policy: Option<PasswordPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_complexity_options: Option<PasswordComplexityOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_history: Option<PasswordHistory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_no_personal_info: Option<PasswordNoPersonalInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_dictionary: Option<PasswordDictionary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_username: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brute_force_protection: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_signup: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_mode: Option<bool>,
+    #[serde(
+        rename = "enabledDatabaseCustomization",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub enabled_database_customization: Option<bool>,
+    #[serde(rename = "customScripts", skip_serializing_if = "Option::is_none")]
+    pub custom_scripts: Option<serde_json::Map<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mfa: Option<ConnectionMfa>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Options for `samlp` (SAML) connections.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SamlConnectionOptions {
+    #[serde(rename = "signInEndpoint", skip_serializing_if = "Option::is_none")]
+    pub sign_in_endpoint: Option<String>,
+    #[serde(rename = "signOutEndpoint", skip_serializing_if = "Option::is_none")]
+    pub sign_out_endpoint: Option<String>,
+    /// Base64-encoded X.509 signing certificate of the identity provider.
+    #[serde(rename = "signingCert", skip_serializing_if = "Option::is_none")]
+    pub signing_cert: Option<String>,
+    #[serde(rename = "signSAMLRequest", skip_serializing_if = "Option::is_none")]
+    pub sign_saml_request: Option<bool>,
+    /// Signature algorithm, e.g. `rsa-sha256`.
+    #[serde(rename = "signatureAlgorithm", skip_serializing_if = "Option::is_none")]
+    pub signature_algorithm: Option<String>,
+    /// Digest algorithm, e.g. `sha256`.
+    #[serde(rename = "digestAlgorithm", skip_serializing_if = "Option::is_none")]
+    pub digest_algorithm: Option<String>,
+    #[serde(rename = "protocolBinding", skip_serializing_if = "Option::is_none")]
+    pub protocol_binding: Option<String>,
+    #[serde(rename = "disableSignout", skip_serializing_if = "Option::is_none")]
+    pub disable_signout: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_aliases: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id_attribute: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbprints: Option<Vec<String>>,
+    #[serde(rename = "fieldsMap", skip_serializing_if = "Option::is_none")]
+    pub fields_map: Option<serde_json::Map<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debug: Option<bool>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// OIDC communication channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OidcChannel {
+    /// Implicit flow with `form_post` response mode
+    FrontChannel,
+    /// Authorization code flow using the client secret
+    BackChannel,
+}
+
+/// Options for `oidc` connections.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OidcConnectionOptions {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub channel: Option<OidcChannel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discovery_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_secret: Option<SecretString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_endpoint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_endpoint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub userinfo_endpoint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwks_uri: Option<String>,
+    /// Space-separated scopes requested from the provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_aliases: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Options for `waad` (Azure AD / Entra ID) connections.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AzureAdConnectionOptions {
+    /// Azure AD tenant domain, e.g. `contoso.onmicrosoft.com`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_secret: Option<SecretString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_aliases: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waad_protocol: Option<String>,
+    #[serde(rename = "useCommonEndpoint", skip_serializing_if = "Option::is_none")]
+    pub use_common_endpoint: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_api: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub basic_profile: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext_profile: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext_groups: Option<bool>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Options for social connections (Google, GitHub, Facebook, ...).
+///
+/// Provider-specific permission flags (e.g. `"email": true`) are kept in `extra`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SocialConnectionOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_secret: Option<SecretString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_params: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Connection options typed according to the connection's strategy.
+///
+/// Returned by [`Connection::typed_options`](super::connections::Connection::typed_options).
+/// Strategies without a dedicated type, or options that fail to parse, are returned as
+/// [`ConnectionOptions::Other`] with the raw JSON.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum ConnectionOptions {
+    Database(DatabaseConnectionOptions),
+    Saml(SamlConnectionOptions),
+    Oidc(OidcConnectionOptions),
+    AzureAd(AzureAdConnectionOptions),
+    Social(SocialConnectionOptions),
+    Other(serde_json::Value),
+}
+
+impl ConnectionOptions {
+    /// Parse raw options according to `strategy`, falling back to [`ConnectionOptions::Other`].
+    pub fn from_value(strategy: ConnectionStrategy, value: serde_json::Value) -> Self {
+        fn parse<T: serde::de::DeserializeOwned>(
+            value: serde_json::Value,
+            wrap: fn(T) -> ConnectionOptions,
+        ) -> ConnectionOptions {
+            match serde_json::from_value(value.clone()) {
+                Ok(options) => wrap(options),
+                Err(_) => ConnectionOptions::Other(value),
+            }
+        }
+
+        match strategy {
+            ConnectionStrategy::Auth0Database | ConnectionStrategy::Custom => {
+                parse(value, ConnectionOptions::Database)
+            }
+            ConnectionStrategy::Saml | ConnectionStrategy::PingIdentity => {
+                parse(value, ConnectionOptions::Saml)
+            }
+            ConnectionStrategy::Oidc | ConnectionStrategy::Okta => {
+                parse(value, ConnectionOptions::Oidc)
+            }
+            ConnectionStrategy::AzureAd => parse(value, ConnectionOptions::AzureAd),
+            ConnectionStrategy::GoogleOAuth2
+            | ConnectionStrategy::GitHub
+            | ConnectionStrategy::LinkedIn
+            | ConnectionStrategy::Facebook
+            | ConnectionStrategy::WindowsLive
+            | ConnectionStrategy::Salesforce => parse(value, ConnectionOptions::Social),
+            ConnectionStrategy::Adfs | ConnectionStrategy::OneLogin => {
+                ConnectionOptions::Other(value)
+            }
+        }
+    }
+}
+
+macro_rules! impl_options_conversions {
+    ($($ty:ident => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$ty> for ConnectionOptions {
+                fn from(options: $ty) -> Self {
+                    ConnectionOptions::$variant(options)
+                }
+            }
+
+            impl From<$ty> for serde_json::Value {
+                fn from(options: $ty) -> Self {
+                    ConnectionOptions::$variant(options).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_options_conversions! {
+    DatabaseConnectionOptions => Database,
+    SamlConnectionOptions => Saml,
+    OidcConnectionOptions => Oidc,
+    AzureAdConnectionOptions => AzureAd,
+    SocialConnectionOptions => Social,
+}
+
+impl From<ConnectionOptions> for serde_json::Value {
+    fn from(options: ConnectionOptions) -> Self {
+        match options {
+            ConnectionOptions::Other(value) => value,
+            typed => serde_json::to_value(typed)
+                .expect("connection options always serialize to a JSON object"),
+        }
+    }
+}
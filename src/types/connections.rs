@@ -1,6 +1,17 @@
+use std::marker::PhantomData;
+
+use secrecy::SecretString;
 use serde::{Deserialize, Serialize};
 
+use crate::error::Result;
+
+use super::builder::{
+    Missing, Provided, list_setters, option_setters, patch_list_setters, patch_setters,
+    require_changes, validate_connection_name,
+};
+use super::connection_options::ConnectionOptions;
 use super::enums::ConnectionStrategy;
+use super::{ClientId, Patch, Timestamp};
 
 /// Represents an Auth0 connection.
 ///
@@ -22,14 +33,38 @@ pub struct Connection {
     pub options: Option<serde_json::Value>,
 }
 
+impl Connection {
+    /// Get `options` typed according to the connection's `strategy`.
+    ///
+    /// Returns `None` if the connection has no options. Strategies without a dedicated
+    /// type, or options that do not match the expected shape, are returned as
+    /// [`ConnectionOptions::Other`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if let Some(ConnectionOptions::Database(db)) = connection.typed_options() {
+    ///     println!("Password policy: {:?}", db.password_policy);
+    /// }
+    /// ```
+    pub fn typed_options(&self) -> Option<ConnectionOptions> {
+        self.options
+            .clone()
+            .map(|options| ConnectionOptions::from_value(self.strategy, options))
+    }
+}
+
 /// Request payload for creating a new connection.
 ///
+/// Prefer [`CreateConnectionRequest::builder`], which requires `strategy` explicitly and
+/// validates the connection name. `Default` falls back to the `auth0` database strategy.
+///
 /// # Examples
 ///
 /// ```ignore
 /// let conn = CreateConnectionRequest {
-///     name: "My Database".to_string(),
-///     strategy: "auth0".to_string(),
+///     name: "my-database".to_string(),
+///     strategy: ConnectionStrategy::Auth0Database,
 ///     display_name: Some("My Database Connection".to_string()),
 ///     ..Default::default()
 /// };
@@ -72,22 +107,171 @@ impl Default for CreateConnectionRequest {
 
 /// Request payload for updating a connection.
 ///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field.
+///
 /// See the [Auth0 Update Connection documentation](https://auth0.com/docs/api/management/v2#!/Connections/patch_connections_by_id)
 /// for detailed information about connection updates.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct UpdateConnectionRequest {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub options: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enabled_clients: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub realms: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_domain_connection: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub display_name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub options: Patch<serde_json::Value>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub enabled_clients: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub realms: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub is_domain_connection: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub metadata: Patch<serde_json::Value>,
+}
+
+impl CreateConnectionRequest {
+    /// Start building a [`CreateConnectionRequest`].
+    ///
+    /// Both `name` and `strategy` are required before `build()` becomes available, so
+    /// the strategy is never defaulted implicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let request = CreateConnectionRequest::builder()
+    ///     .name("my-database")
+    ///     .strategy(ConnectionStrategy::Auth0Database)
+    ///     .enabled_clients(["client_123"])
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> CreateConnectionRequestBuilder<Missing, Missing> {
+        CreateConnectionRequestBuilder {
+            request: CreateConnectionRequest::default(),
+            _state: PhantomData,
+        }
+    }
+}
+
+/// Fluent builder for [`CreateConnectionRequest`].
+///
+/// The `N` and `S` parameters track whether `name` and `strategy` have been set.
+#[derive(Debug, Clone)]
+pub struct CreateConnectionRequestBuilder<N, S> {
+    request: CreateConnectionRequest,
+    _state: PhantomData<(N, S)>,
+}
+
+impl<S> CreateConnectionRequestBuilder<Missing, S> {
+    /// Set the connection name.
+    pub fn name(mut self, name: impl Into<String>) -> CreateConnectionRequestBuilder<Provided, S> {
+        self.request.name = name.into();
+        CreateConnectionRequestBuilder {
+            request: self.request,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<N> CreateConnectionRequestBuilder<N, Missing> {
+    /// Set the connection strategy.
+    pub fn strategy(
+        mut self,
+        strategy: ConnectionStrategy,
+    ) -> CreateConnectionRequestBuilder<N, Provided> {
+        self.request.strategy = strategy;
+        CreateConnectionRequestBuilder {
+            request: self.request,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<N, S> CreateConnectionRequestBuilder<N, S> {
+    option_setters! {
+        /// Set the name shown on the login page.
+        display_name: String,
+        /// Set strategy-specific options.
+        options: serde_json::Value,
+        /// Set whether this is a domain-level connection.
+        is_domain_connection: bool,
+        /// Set connection metadata.
+        metadata: serde_json::Value,
+    }
+
+    list_setters! {
+        /// Set the clients this connection is enabled for.
+        enabled_clients,
+        /// Set the realms used for authentication.
+        realms,
+    }
+}
+
+impl CreateConnectionRequestBuilder<Provided, Provided> {
+    /// Validate and build the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Configuration` if `name` does not follow Auth0's connection
+    /// naming rules.
+    pub fn build(self) -> Result<CreateConnectionRequest> {
+        validate_connection_name(&self.request.name)?;
+        Ok(self.request)
+    }
+}
+
+impl UpdateConnectionRequest {
+    /// Start building an [`UpdateConnectionRequest`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let request = UpdateConnectionRequest::builder()
+    ///     .display_name("Employees")
+    ///     .clear_metadata()
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> UpdateConnectionRequestBuilder {
+        UpdateConnectionRequestBuilder {
+            request: UpdateConnectionRequest::default(),
+        }
+    }
+}
+
+/// Fluent builder for [`UpdateConnectionRequest`].
+///
+/// Each scalar setter has a matching `clear_*` method that sends `null` for the field.
+#[derive(Debug, Clone)]
+pub struct UpdateConnectionRequestBuilder {
+    request: UpdateConnectionRequest,
+}
+
+impl UpdateConnectionRequestBuilder {
+    patch_setters! {
+        /// Set the name shown on the login page.
+        display_name / clear_display_name: String,
+        /// Set strategy-specific options.
+        options: serde_json::Value,
+        /// Set whether this is a domain-level connection.
+        is_domain_connection: bool,
+        /// Set connection metadata.
+        metadata / clear_metadata: serde_json::Value,
+    }
+
+    patch_list_setters! {
+        /// Set the clients this connection is enabled for.
+        enabled_clients,
+        /// Set the realms used for authentication.
+        realms,
+    }
+
+    /// Validate and build the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Configuration` if no field is changed.
+    pub fn build(self) -> Result<UpdateConnectionRequest> {
+        require_changes("connection update", &self.request)?;
+        Ok(self.request)
+    }
 }
 
 /// Query parameters for listing connections.
@@ -124,3 +308,117 @@ pub struct ConnectionsPage {
     /// Total number of connections matching the query.
     pub total: u32,
 }
+
+/// A client that a connection is enabled for.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnabledClient {
+    pub client_id: ClientId,
+}
+
+/// Page of clients a connection is enabled for.
+///
+/// Uses checkpoint pagination: pass `next` as `from` to fetch the following page.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnabledClientsPage {
+    pub clients: Vec<EnabledClient>,
+    /// Checkpoint for the next page, absent on the last page.
+    pub next: Option<String>,
+}
+
+/// Change to a connection's enabled state for one client.
+#[derive(Debug, Clone, Serialize)]
+pub struct EnabledClientUpdate {
+    pub client_id: ClientId,
+    /// `true` to enable the connection for the client, `false` to disable it.
+    pub status: bool,
+}
+
+impl EnabledClientUpdate {
+    /// Enable the connection for a client.
+    pub fn enable(client_id: ClientId) -> Self {
+        Self {
+            client_id,
+            status: true,
+        }
+    }
+
+    /// Disable the connection for a client.
+    pub fn disable(client_id: ClientId) -> Self {
+        Self {
+            client_id,
+            status: false,
+        }
+    }
+}
+
+/// Mapping between an Auth0 user attribute and a SCIM attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScimMapping {
+    /// Auth0 user attribute, e.g. `given_name`.
+    pub auth0: String,
+    /// SCIM attribute, e.g. `name.givenName`.
+    pub scim: String,
+}
+
+/// SCIM provisioning configuration of an enterprise connection.
+///
+/// See the [Auth0 SCIM documentation](https://auth0.com/docs/authenticate/protocols/scim)
+/// for detailed information about inbound SCIM.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimConfiguration {
+    pub connection_id: String,
+    pub connection_name: Option<String>,
+    pub strategy: Option<ConnectionStrategy>,
+    pub tenant_name: Option<String>,
+    pub user_id_attribute: Option<String>,
+    pub mapping: Option<Vec<ScimMapping>>,
+    pub created_at: Option<Timestamp>,
+    pub updated_on: Option<Timestamp>,
+}
+
+/// Request payload for creating or updating a SCIM configuration.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ScimConfigurationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id_attribute: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mapping: Option<Vec<ScimMapping>>,
+}
+
+/// Default SCIM attribute mapping for a connection's strategy.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimDefaultMapping {
+    pub mapping: Vec<ScimMapping>,
+}
+
+/// A SCIM bearer token, as returned when listing tokens.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimToken {
+    pub token_id: String,
+    pub scopes: Option<Vec<String>>,
+    pub created_at: Option<Timestamp>,
+    pub valid_until: Option<Timestamp>,
+    pub last_used_at: Option<Timestamp>,
+}
+
+/// Request payload for creating a SCIM bearer token.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateScimTokenRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+    /// Token lifetime in seconds. Omit for a non-expiring token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime: Option<u64>,
+}
+
+/// A newly created SCIM bearer token.
+///
+/// The `token` value is only returned once, at creation time.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimTokenCreated {
+    pub token_id: String,
+    pub token: SecretString,
+    pub scopes: Option<Vec<String>>,
+    pub created_at: Option<Timestamp>,
+    pub valid_until: Option<Timestamp>,
+}
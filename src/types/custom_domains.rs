@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Patch, Timestamp};
+
+/// Who provisions the TLS certificate of a custom domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomDomainType {
+    /// Auth0 provisions and renews the certificate.
+    Auth0ManagedCerts,
+    /// The domain is served through a reverse proxy holding your own certificate.
+    SelfManagedCerts,
+}
+
+/// Provisioning state of a custom domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomDomainStatus {
+    Disabled,
+    Pending,
+    /// Waiting for the DNS verification record to be found.
+    PendingVerification,
+    /// Verified and serving traffic.
+    Ready,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// TLS policy applied to a custom domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TlsPolicy {
+    /// TLS 1.2 and later with modern ciphers.
+    Recommended,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Header carrying the end user's IP address for self-managed custom domains.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CustomClientIpHeader {
+    TrueClientIp,
+    CfConnectingIp,
+    XForwardedFor,
+    XAzureClientip,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// DNS record type used to prove ownership of a custom domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationMethodType {
+    Cname,
+    Txt,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A DNS record to create to verify a custom domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerificationMethod {
+    pub name: VerificationMethodType,
+    /// Value of the record.
+    pub record: String,
+    /// Name of the record, when it differs from the custom domain itself.
+    pub domain: Option<String>,
+}
+
+/// Verification state of a custom domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DomainVerification {
+    #[serde(default)]
+    pub methods: Vec<VerificationMethod>,
+    pub status: Option<String>,
+    pub error_msg: Option<String>,
+    pub last_verified_at: Option<Timestamp>,
+}
+
+/// Represents an Auth0 custom domain.
+///
+/// See the [Auth0 Custom Domains documentation](https://auth0.com/docs/customize/custom-domains)
+/// for detailed information about custom domains.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomDomain {
+    pub custom_domain_id: String,
+    pub domain: String,
+    #[serde(default)]
+    pub primary: bool,
+    pub status: CustomDomainStatus,
+    #[serde(rename = "type")]
+    pub domain_type: CustomDomainType,
+    /// Target of the CNAME record for self-managed domains.
+    pub origin_domain_name: Option<String>,
+    pub verification: Option<DomainVerification>,
+    pub tls_policy: Option<TlsPolicy>,
+    pub custom_client_ip_header: Option<CustomClientIpHeader>,
+}
+
+impl CustomDomain {
+    /// Whether the domain is verified and serving traffic.
+    pub fn is_ready(&self) -> bool {
+        self.status == CustomDomainStatus::Ready
+    }
+
+    /// DNS records to create to verify the domain.
+    pub fn verification_methods(&self) -> &[VerificationMethod] {
+        self.verification
+            .as_ref()
+            .map(|v| v.methods.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Request payload for creating a custom domain.
+///
+/// # Examples
+///
+/// ```ignore
+/// let request = CreateCustomDomainRequest::new("login.example.com", CustomDomainType::Auth0ManagedCerts);
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateCustomDomainRequest {
+    pub domain: String,
+    #[serde(rename = "type")]
+    pub domain_type: CustomDomainType,
+    /// Only `txt` is accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<VerificationMethodType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_policy: Option<TlsPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_client_ip_header: Option<CustomClientIpHeader>,
+}
+
+impl CreateCustomDomainRequest {
+    /// Create a request for the given domain and certificate type.
+    pub fn new(domain: impl Into<String>, domain_type: CustomDomainType) -> Self {
+        Self {
+            domain: domain.into(),
+            domain_type,
+            verification_method: None,
+            tls_policy: None,
+            custom_client_ip_header: None,
+        }
+    }
+}
+
+/// Request payload for updating a custom domain.
+///
+/// Fields left unset are omitted; [`Patch::Null`] on `custom_client_ip_header` clears it.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateCustomDomainRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_policy: Option<TlsPolicy>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub custom_client_ip_header: Patch<CustomClientIpHeader>,
+}
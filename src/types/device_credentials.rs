@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClientId, UserId};
+
+/// Kind of credential stored for a device.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceCredentialType {
+    /// Public key used for signing in from a native app.
+    PublicKey,
+    RefreshToken,
+    RotatingRefreshToken,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A credential issued to a user's device.
+///
+/// The credential value itself is never returned.
+///
+/// See the [Auth0 Device Credentials documentation](https://auth0.com/docs/api/management/v2/device-credentials/get-device-credentials)
+/// for detailed information about device credentials.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceCredential {
+    pub id: String,
+    pub device_name: Option<String>,
+    /// Unique identifier of the device.
+    pub device_id: Option<String>,
+    #[serde(rename = "type")]
+    pub credential_type: Option<DeviceCredentialType>,
+    pub user_id: Option<UserId>,
+    pub client_id: Option<ClientId>,
+}
+
+/// Request payload for registering a device public key.
+///
+/// # Examples
+///
+/// ```ignore
+/// let request = CreatePublicKeyRequest {
+///     client_id: Some(ClientId::new("client_123")),
+///     ..CreatePublicKeyRequest::new("Pixel 8", "-----BEGIN PUBLIC KEY-----...", "device_abc")
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatePublicKeyRequest {
+    pub device_name: String,
+    /// Base64-encoded public key.
+    pub value: String,
+    pub device_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<ClientId>,
+    /// Set to `PublicKey` by [`CreatePublicKeyRequest::new`]; the only type Auth0 accepts.
+    #[serde(rename = "type")]
+    pub credential_type: DeviceCredentialType,
+}
+
+impl CreatePublicKeyRequest {
+    /// Create a public key credential for the given device, leaving the client unset.
+    pub fn new(
+        device_name: impl Into<String>,
+        value: impl Into<String>,
+        device_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            device_name: device_name.into(),
+            value: value.into(),
+            device_id: device_id.into(),
+            client_id: None,
+            credential_type: DeviceCredentialType::PublicKey,
+        }
+    }
+}
+
+/// Identifier of a newly created device credential.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreatedDeviceCredential {
+    pub id: String,
+}
+
+/// Query parameters for listing device credentials.
+///
+/// See the [Auth0 Get Device Credentials documentation](https://auth0.com/docs/api/management/v2/device-credentials/get-device-credentials)
+/// for detailed information about available filters.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListDeviceCredentialsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<ClientId>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub credential_type: Option<DeviceCredentialType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<bool>,
+}
+
+/// Paginated response for device credential list operations.
+///
+/// Returned when `include_totals` is set to `true` in list parameters.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceCredentialsPage {
+    /// List of device credentials in this page.
+    pub device_credentials: Vec<DeviceCredential>,
+    /// Starting index of this page (zero-based).
+    pub start: u32,
+    /// Maximum number of results per page.
+    pub limit: u32,
+    /// Total number of device credentials matching the query.
+    pub total: u32,
+}
@@ -0,0 +1,337 @@
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+use super::{Patch, serialize_optional_secret};
+
+/// Credentials for Amazon SES.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesCredentials {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_key_id: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub secret_access_key: Option<SecretString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+/// Credentials for a generic SMTP server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpCredentials {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_host: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_user: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub smtp_pass: Option<SecretString>,
+}
+
+/// Credentials for providers authenticated by a single API key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyCredentials {
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub api_key: Option<SecretString>,
+}
+
+/// Credentials for SparkPost.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SparkPostCredentials {
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub api_key: Option<SecretString>,
+    /// `eu` for the EU region; unset for the US region.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+/// Credentials for Mailgun.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MailgunCredentials {
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub api_key: Option<SecretString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// `eu` for the EU region; unset for the US region.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+/// Credentials for Azure Communication Services.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AzureCsCredentials {
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub connection_string: Option<SecretString>,
+}
+
+/// Credentials for Microsoft 365.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ms365Credentials {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_secret: Option<SecretString>,
+}
+
+/// Email provider and its credentials, keyed by the provider `name`.
+///
+/// Auth0 never returns secrets, so secret fields are `None` on providers read back from
+/// the API. Providers not modelled here deserialize as [`EmailProviderCredentials::Other`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "name", content = "credentials", rename_all = "snake_case")]
+pub enum EmailProviderCredentials {
+    Smtp(SmtpCredentials),
+    Ses(SesCredentials),
+    Sendgrid(ApiKeyCredentials),
+    Mandrill(ApiKeyCredentials),
+    Sparkpost(SparkPostCredentials),
+    Mailgun(MailgunCredentials),
+    AzureCs(AzureCsCredentials),
+    Ms365(Ms365Credentials),
+    #[serde(untagged)]
+    Other {
+        #[serde(rename = "name")]
+        provider: String,
+        #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+        credentials: serde_json::Value,
+    },
+}
+
+/// Represents the tenant's email provider.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailProvider {
+    #[serde(flatten)]
+    pub credentials: EmailProviderCredentials,
+    pub enabled: Option<bool>,
+    pub default_from_address: Option<String>,
+    /// Provider-specific settings, e.g. SES message tags or SMTP headers.
+    pub settings: Option<serde_json::Value>,
+}
+
+/// Request payload for configuring the email provider.
+///
+/// # Examples
+///
+/// ```ignore
+/// let request = CreateEmailProviderRequest {
+///     default_from_address: Some("no-reply@example.com".into()),
+///     ..CreateEmailProviderRequest::new(EmailProviderCredentials::Sendgrid(ApiKeyCredentials {
+///         api_key: Some("SG.xxxx".into()),
+///     }))
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateEmailProviderRequest {
+    #[serde(flatten)]
+    pub credentials: EmailProviderCredentials,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_from_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<serde_json::Value>,
+}
+
+impl CreateEmailProviderRequest {
+    /// Create a request for the given provider, leaving the other fields unset.
+    pub fn new(credentials: EmailProviderCredentials) -> Self {
+        Self {
+            credentials,
+            enabled: None,
+            default_from_address: None,
+            settings: None,
+        }
+    }
+}
+
+/// Request payload for updating the email provider.
+///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field. Setting `credentials` sends both the provider name and its
+/// credentials.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateEmailProviderRequest {
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<EmailProviderCredentials>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub enabled: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub default_from_address: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub settings: Patch<serde_json::Value>,
+}
+
+/// Name of an email template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTemplateName {
+    VerifyEmail,
+    VerifyEmailByCode,
+    ResetEmail,
+    ResetEmailByCode,
+    WelcomeEmail,
+    BlockedAccount,
+    StolenCredentials,
+    EnrollmentEmail,
+    MfaOobCode,
+    UserInvitation,
+    AsyncApproval,
+    /// Legacy template replaced by `reset_email`.
+    ChangePassword,
+    /// Legacy template replaced by `reset_email`.
+    PasswordReset,
+}
+
+impl EmailTemplateName {
+    /// The template name as used in API paths.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::VerifyEmail => "verify_email",
+            Self::VerifyEmailByCode => "verify_email_by_code",
+            Self::ResetEmail => "reset_email",
+            Self::ResetEmailByCode => "reset_email_by_code",
+            Self::WelcomeEmail => "welcome_email",
+            Self::BlockedAccount => "blocked_account",
+            Self::StolenCredentials => "stolen_credentials",
+            Self::EnrollmentEmail => "enrollment_email",
+            Self::MfaOobCode => "mfa_oob_code",
+            Self::UserInvitation => "user_invitation",
+            Self::AsyncApproval => "async_approval",
+            Self::ChangePassword => "change_password",
+            Self::PasswordReset => "password_reset",
+        }
+    }
+}
+
+/// Represents an email template.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTemplate {
+    pub template: EmailTemplateName,
+    pub body: Option<String>,
+    pub from: Option<String>,
+    pub subject: Option<String>,
+    /// Template language. Only `liquid` is supported.
+    pub syntax: Option<String>,
+    /// Where users are redirected after the action in the email.
+    pub result_url: Option<String>,
+    pub url_lifetime_in_seconds: Option<u64>,
+    #[serde(rename = "includeEmailInRedirect")]
+    pub include_email_in_redirect: Option<bool>,
+    pub enabled: Option<bool>,
+}
+
+/// Request payload for creating an email template.
+///
+/// # Examples
+///
+/// ```ignore
+/// let request = CreateEmailTemplateRequest {
+///     result_url: Some("https://example.com/verified".into()),
+///     ..CreateEmailTemplateRequest::new(
+///         EmailTemplateName::VerifyEmail,
+///         "Verify your email",
+///         "<html>{{ url }}</html>",
+///         "Example <no-reply@example.com>",
+///     )
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEmailTemplateRequest {
+    pub template: EmailTemplateName,
+    pub subject: String,
+    pub body: String,
+    pub from: String,
+    pub syntax: String,
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_lifetime_in_seconds: Option<u64>,
+    #[serde(
+        rename = "includeEmailInRedirect",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub include_email_in_redirect: Option<bool>,
+}
+
+impl CreateEmailTemplateRequest {
+    /// Create an enabled Liquid template.
+    pub fn new(
+        template: EmailTemplateName,
+        subject: impl Into<String>,
+        body: impl Into<String>,
+        from: impl Into<String>,
+    ) -> Self {
+        Self {
+            template,
+            subject: subject.into(),
+            body: body.into(),
+            from: from.into(),
+            syntax: "liquid".to_string(),
+            enabled: true,
+            result_url: None,
+            url_lifetime_in_seconds: None,
+            include_email_in_redirect: None,
+        }
+    }
+}
+
+/// Request payload for updating an email template.
+///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEmailTemplateRequest {
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub subject: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub body: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub from: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub enabled: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub result_url: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub url_lifetime_in_seconds: Patch<u64>,
+    #[serde(
+        rename = "includeEmailInRedirect",
+        skip_serializing_if = "Patch::is_unchanged"
+    )]
+    pub include_email_in_redirect: Patch<bool>,
+}
@@ -220,3 +220,78 @@ pub enum LogEventType {
     #[serde(rename = "fcc")]
     FailedCodeExchange,
 }
+
+/// Categories that group [`LogEventType`] codes.
+///
+/// Used to filter which events a log stream delivers.
+/// See the [Auth0 Log Streams documentation](https://auth0.com/docs/customize/log-streams/event-filters)
+/// for the event types in each category.
+///
+/// Categories not modelled here deserialize as [`LogEventCategory::Unknown`]; the
+/// `Other` variant is Auth0's own `other` category.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LogEventCategory {
+    /// Login failures
+    #[serde(rename = "auth.login.fail")]
+    AuthLoginFail,
+    /// Login notifications
+    #[serde(rename = "auth.login.notification")]
+    AuthLoginNotification,
+    /// Login successes
+    #[serde(rename = "auth.login.success")]
+    AuthLoginSuccess,
+    /// Logout failures
+    #[serde(rename = "auth.logout.fail")]
+    AuthLogoutFail,
+    /// Logout successes
+    #[serde(rename = "auth.logout.success")]
+    AuthLogoutSuccess,
+    /// Signup failures
+    #[serde(rename = "auth.signup.fail")]
+    AuthSignupFail,
+    /// Signup successes
+    #[serde(rename = "auth.signup.success")]
+    AuthSignupSuccess,
+    /// Silent authentication failures
+    #[serde(rename = "auth.silent_auth.fail")]
+    AuthSilentAuthFail,
+    /// Silent authentication successes
+    #[serde(rename = "auth.silent_auth.success")]
+    AuthSilentAuthSuccess,
+    /// Token exchange failures
+    #[serde(rename = "auth.token_exchange.fail")]
+    AuthTokenExchangeFail,
+    /// Token exchange successes
+    #[serde(rename = "auth.token_exchange.success")]
+    AuthTokenExchangeSuccess,
+    /// Management API failures
+    #[serde(rename = "management.fail")]
+    ManagementFail,
+    /// Management API successes
+    #[serde(rename = "management.success")]
+    ManagementSuccess,
+    /// SCIM events
+    #[serde(rename = "scim.event")]
+    ScimEvent,
+    /// System notifications
+    #[serde(rename = "system.notification")]
+    SystemNotification,
+    /// User operation failures
+    #[serde(rename = "user.fail")]
+    UserFail,
+    /// User notifications
+    #[serde(rename = "user.notification")]
+    UserNotification,
+    /// User operation successes
+    #[serde(rename = "user.success")]
+    UserSuccess,
+    /// Actions execution events
+    #[serde(rename = "actions")]
+    Actions,
+    /// Events not in any other category
+    #[serde(rename = "other")]
+    Other,
+    /// A category not known to this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClientId, UserId};
+
+/// Consent a user gave an application to access an API on their behalf.
+///
+/// See the [Auth0 Grants documentation](https://auth0.com/docs/api/management/v2/grants/get-grants)
+/// for detailed information about grants.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Grant {
+    pub id: String,
+    #[serde(rename = "clientID")]
+    pub client_id: Option<ClientId>,
+    pub user_id: Option<UserId>,
+    /// Identifier of the API the grant is for.
+    pub audience: Option<String>,
+    #[serde(default)]
+    pub scope: Vec<String>,
+}
+
+/// Query parameters for listing grants.
+///
+/// See the [Auth0 Get Grants documentation](https://auth0.com/docs/api/management/v2/grants/get-grants)
+/// for detailed information about available filters.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListGrantsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<ClientId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+}
+
+/// Paginated response for grant list operations.
+///
+/// Returned when `include_totals` is set to `true` in list parameters.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GrantsPage {
+    /// List of grants in this page.
+    pub grants: Vec<Grant>,
+    /// Starting index of this page (zero-based).
+    pub start: u32,
+    /// Maximum number of results per page.
+    pub limit: u32,
+    /// Total number of grants matching the query.
+    pub total: u32,
+}
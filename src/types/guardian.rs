@@ -0,0 +1,208 @@
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+use super::{UserId, serialize_optional_secret};
+
+/// A multi-factor authentication factor.
+///
+/// Factors not modelled here are kept as [`GuardianFactorName::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GuardianFactorName {
+    PushNotification,
+    Sms,
+    Email,
+    Duo,
+    Otp,
+    WebauthnRoaming,
+    WebauthnPlatform,
+    RecoveryCode,
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl GuardianFactorName {
+    /// The factor name as used in API paths.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::PushNotification => "push-notification",
+            Self::Sms => "sms",
+            Self::Email => "email",
+            Self::Duo => "duo",
+            Self::Otp => "otp",
+            Self::WebauthnRoaming => "webauthn-roaming",
+            Self::WebauthnPlatform => "webauthn-platform",
+            Self::RecoveryCode => "recovery-code",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+/// An MFA factor and whether it is enabled.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuardianFactor {
+    pub name: GuardianFactorName,
+    pub enabled: bool,
+    /// Whether the factor's trial period has expired.
+    pub trial_expired: Option<bool>,
+}
+
+/// When users are prompted for MFA.
+///
+/// An empty policy list means MFA is only required where rules or actions ask for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MfaPolicy {
+    /// Require MFA on every login to every application.
+    AllApplications,
+    /// Require MFA only when the login looks risky.
+    ConfidenceScore,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Provider delivering SMS and voice messages for the phone factor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PhoneProvider {
+    Auth0,
+    Twilio,
+    /// Messages are sent by a `send-phone-message` action or hook.
+    PhoneMessageHook,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Twilio configuration for the phone factor.
+///
+/// Set either `from` or `messaging_service_sid`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TwilioConfig {
+    /// Phone number messages are sent from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messaging_service_sid: Option<String>,
+    /// Twilio account SID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub auth_token: Option<SecretString>,
+}
+
+/// Service delivering push notifications for the push factor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PushProvider {
+    /// The Auth0 Guardian app.
+    Guardian,
+    /// Amazon SNS, for a custom app built on the Guardian SDK.
+    Sns,
+    /// Direct delivery through APNs and FCM.
+    Direct,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Amazon SNS configuration for push notifications.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnsConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_access_key_id: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_secret_access_key: Option<SecretString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sns_apns_platform_application_arn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sns_gcm_platform_application_arn: Option<String>,
+}
+
+/// Apple Push Notification service configuration for direct push delivery.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApnsConfig {
+    /// Use the APNs sandbox environment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<String>,
+    /// Base64-encoded PKCS#12 certificate. Write-only.
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub p12: Option<SecretString>,
+}
+
+/// Firebase Cloud Messaging configuration for direct push delivery.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FcmConfig {
+    /// FCM server key. Write-only.
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub server_key: Option<SecretString>,
+}
+
+/// Request payload for creating an MFA enrollment ticket.
+///
+/// # Examples
+///
+/// ```ignore
+/// let request = CreateEnrollmentTicketRequest {
+///     send_mail: Some(false),
+///     factor: Some(GuardianFactorName::Otp),
+///     ..CreateEnrollmentTicketRequest::new(UserId::new("auth0|123"))
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateEnrollmentTicketRequest {
+    pub user_id: UserId,
+    /// Email to send the ticket to, when different from the user's email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Whether Auth0 emails the ticket to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_mail: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_locale: Option<String>,
+    /// Factor to enroll. The user chooses when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub factor: Option<GuardianFactorName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_multiple_enrollments: Option<bool>,
+}
+
+impl CreateEnrollmentTicketRequest {
+    /// Create a ticket request for the given user, leaving the other fields unset.
+    pub fn new(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            email: None,
+            send_mail: None,
+            email_locale: None,
+            factor: None,
+            allow_multiple_enrollments: None,
+        }
+    }
+}
+
+/// An MFA enrollment ticket.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnrollmentTicket {
+    pub ticket_id: String,
+    /// URL the user opens to enroll.
+    pub ticket_url: String,
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::Patch;
+
+/// Extensibility point a hook runs at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HookTrigger {
+    /// Runs when an access token is issued via the Client Credentials grant.
+    CredentialsExchange,
+    PreUserRegistration,
+    PostUserRegistration,
+    PostChangePassword,
+    SendPhoneMessage,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Represents an Auth0 Hook.
+///
+/// Hooks are deprecated in favour of Actions; see the
+/// [Auth0 migration guide](https://auth0.com/docs/customize/actions/migrate/migrate-from-hooks-to-actions).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hook {
+    pub id: String,
+    pub name: Option<String>,
+    pub script: Option<String>,
+    pub trigger_id: Option<HookTrigger>,
+    pub enabled: Option<bool>,
+    /// npm dependencies, keyed by package name with the version as value.
+    #[serde(default)]
+    pub dependencies: HashMap<String, String>,
+}
+
+/// Request payload for creating a new hook.
+///
+/// # Examples
+///
+/// ```ignore
+/// let script = "module.exports = function (user, context, cb) { cb(null, { user }); };";
+/// let request = CreateHookRequest {
+///     enabled: Some(true),
+///     ..CreateHookRequest::new("add-locale", script, HookTrigger::PreUserRegistration)
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateHookRequest {
+    pub name: String,
+    pub script: String,
+    pub trigger_id: HookTrigger,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<HashMap<String, String>>,
+}
+
+impl CreateHookRequest {
+    /// Create a hook with the given name, script and trigger, leaving enabled unset.
+    pub fn new(name: impl Into<String>, script: impl Into<String>, trigger: HookTrigger) -> Self {
+        Self {
+            name: name.into(),
+            script: script.into(),
+            trigger_id: trigger,
+            enabled: None,
+            dependencies: None,
+        }
+    }
+}
+
+/// Request payload for updating an existing hook.
+///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field. The trigger cannot be changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateHookRequest {
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub script: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub enabled: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub dependencies: Patch<HashMap<String, String>>,
+}
+
+/// Query parameters for listing hooks.
+///
+/// See the [Auth0 Get Hooks documentation](https://auth0.com/docs/api/management/v2/hooks/get-hooks)
+/// for detailed information about available filters.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListHooksParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(rename = "triggerId", skip_serializing_if = "Option::is_none")]
+    pub trigger_id: Option<HookTrigger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+}
+
+/// Paginated response for hook list operations.
+///
+/// Returned when `include_totals` is set to `true` in list parameters.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HooksPage {
+    /// List of hooks in this page.
+    pub hooks: Vec<Hook>,
+    /// Starting index of this page (zero-based).
+    pub start: u32,
+    /// Maximum number of results per page.
+    pub limit: u32,
+    /// Total number of hooks matching the query.
+    pub total: u32,
+}
@@ -0,0 +1,207 @@
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use super::Timestamp;
+use crate::error::{Auth0Error, Result};
+
+/// A key Auth0 uses to sign tokens for the tenant.
+///
+/// At any time one key is `current`, one may be `next` (used after the next rotation), and
+/// one may be `previous` (still published so tokens it signed can be verified).
+///
+/// See the [Auth0 Signing Keys documentation](https://auth0.com/docs/get-started/tenant-settings/signing-keys)
+/// for detailed information about key rotation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SigningKey {
+    /// Key ID, matching the `kid` header of signed tokens.
+    pub kid: String,
+    /// PEM-encoded X.509 certificate.
+    pub cert: String,
+    /// PKCS#7-encoded certificate.
+    pub pkcs7: Option<String>,
+    #[serde(default)]
+    pub current: bool,
+    #[serde(default)]
+    pub next: bool,
+    #[serde(default)]
+    pub previous: bool,
+    pub current_since: Option<Timestamp>,
+    pub current_until: Option<Timestamp>,
+    /// Colon-separated hex SHA-1 fingerprint of the certificate.
+    pub fingerprint: Option<String>,
+    /// Hex SHA-1 thumbprint of the certificate.
+    pub thumbprint: Option<String>,
+    #[serde(default)]
+    pub revoked: bool,
+    pub revoked_at: Option<Timestamp>,
+}
+
+impl SigningKey {
+    /// Compute the JSON Web Key Auth0 publishes for this key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::InvalidCertificate` if `cert` is not a PEM certificate with an
+    /// RSA public key.
+    pub fn to_jwk(&self) -> Result<Jwk> {
+        let der = decode_pem(&self.cert)?;
+        let (n, e) = rsa_public_key(&der).ok_or_else(|| {
+            Auth0Error::InvalidCertificate(format!(
+                "signing key {} does not hold an RSA public key",
+                self.kid
+            ))
+        })?;
+
+        Ok(Jwk {
+            kty: "RSA".to_string(),
+            alg: "RS256".to_string(),
+            key_use: "sig".to_string(),
+            kid: self.kid.clone(),
+            n: URL_SAFE_NO_PAD.encode(n),
+            e: URL_SAFE_NO_PAD.encode(e),
+            x5c: vec![STANDARD.encode(&der)],
+            x5t: URL_SAFE_NO_PAD.encode(Sha1::digest(&der)),
+        })
+    }
+}
+
+/// Certificate and key ID returned when a signing key is rotated or revoked.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SigningKeyCert {
+    pub kid: String,
+    /// PEM-encoded X.509 certificate.
+    pub cert: String,
+}
+
+/// An RSA JSON Web Key, as published at `/.well-known/jwks.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    pub alg: String,
+    #[serde(rename = "use")]
+    pub key_use: String,
+    pub kid: String,
+    /// Base64url-encoded modulus.
+    pub n: String,
+    /// Base64url-encoded public exponent.
+    pub e: String,
+    /// Base64-encoded DER certificate chain.
+    pub x5c: Vec<String>,
+    /// Base64url-encoded SHA-1 thumbprint of the certificate.
+    pub x5t: String,
+}
+
+/// A JSON Web Key Set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwks {
+    pub keys: Vec<Jwk>,
+}
+
+impl Jwks {
+    /// Build the key set for the given signing keys, skipping revoked keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::InvalidCertificate` if a key's certificate cannot be decoded.
+    pub fn from_signing_keys(keys: &[SigningKey]) -> Result<Self> {
+        let keys = keys
+            .iter()
+            .filter(|key| !key.revoked)
+            .map(SigningKey::to_jwk)
+            .collect::<Result<_>>()?;
+        Ok(Self { keys })
+    }
+}
+
+fn decode_pem(pem: &str) -> Result<Vec<u8>> {
+    let body: String = pem
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("-----"))
+        .collect();
+
+    STANDARD
+        .decode(body)
+        .map_err(|e| Auth0Error::InvalidCertificate(format!("invalid PEM: {}", e)))
+}
+
+/// DER encoding of the rsaEncryption algorithm OID (1.2.840.113549.1.1.1).
+const RSA_ENCRYPTION_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01];
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_EXPLICIT_VERSION: u8 = 0xa0;
+
+/// Split one DER element off `input`, returning its tag, contents and the remainder.
+fn read_element(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first, rest) = rest.split_first()?;
+
+    let (len, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > 4 || rest.len() < count {
+            return None;
+        }
+        let len = rest[..count]
+            .iter()
+            .fold(0usize, |len, &b| (len << 8) | b as usize);
+        (len, &rest[count..])
+    };
+
+    if rest.len() < len {
+        return None;
+    }
+    Some((tag, &rest[..len], &rest[len..]))
+}
+
+fn expect_element(input: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    let (actual, contents, rest) = read_element(input)?;
+    (actual == tag).then_some((contents, rest))
+}
+
+fn unsigned(integer: &[u8]) -> &[u8] {
+    let zeros = integer
+        .iter()
+        .take(integer.len().saturating_sub(1))
+        .take_while(|&&b| b == 0)
+        .count();
+    &integer[zeros..]
+}
+
+/// Extract the RSA modulus and exponent from a DER-encoded X.509 certificate.
+fn rsa_public_key(der: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (certificate, _) = expect_element(der, TAG_SEQUENCE)?;
+    let (mut tbs, _) = expect_element(certificate, TAG_SEQUENCE)?;
+
+    if tbs.first() == Some(&TAG_EXPLICIT_VERSION) {
+        tbs = read_element(tbs)?.2;
+    }
+    // serialNumber, signature, issuer, validity, subject
+    for _ in 0..5 {
+        tbs = read_element(tbs)?.2;
+    }
+
+    let (spki, _) = expect_element(tbs, TAG_SEQUENCE)?;
+    let (algorithm, spki) = expect_element(spki, TAG_SEQUENCE)?;
+    let (oid, _) = expect_element(algorithm, TAG_OID)?;
+    if oid != RSA_ENCRYPTION_OID {
+        return None;
+    }
+
+    let (bits, _) = expect_element(spki, TAG_BIT_STRING)?;
+    let (&unused_bits, public_key) = bits.split_first()?;
+    if unused_bits != 0 {
+        return None;
+    }
+
+    let (rsa_key, _) = expect_element(public_key, TAG_SEQUENCE)?;
+    let (n, rsa_key) = expect_element(rsa_key, TAG_INTEGER)?;
+    let (e, _) = expect_element(rsa_key, TAG_INTEGER)?;
+    Some((unsigned(n), unsigned(e)))
+}
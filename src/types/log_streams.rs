@@ -0,0 +1,324 @@
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::{LogEventCategory, Patch, serialize_optional_secret};
+
+/// Delivery state of a log stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStreamStatus {
+    /// Events are being delivered.
+    Active,
+    /// Delivery was paused by a user.
+    Paused,
+    /// Delivery was stopped by Auth0 after repeated failures.
+    Suspended,
+}
+
+/// Body format used by HTTP log streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpContentFormat {
+    /// A JSON array of events per request.
+    JsonArray,
+    /// One JSON event per line.
+    JsonLines,
+    /// A single JSON event per request.
+    JsonObject,
+}
+
+/// A custom header sent with every HTTP log stream request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpCustomHeader {
+    pub header: String,
+    pub value: String,
+}
+
+/// Sink configuration for a custom webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpSink {
+    pub http_endpoint: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_content_format: Option<HttpContentFormat>,
+    /// Value Auth0 sends in the `Authorization` header.
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub http_authorization: Option<SecretString>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub http_custom_headers: Vec<HttpCustomHeader>,
+}
+
+/// Sink configuration for Amazon EventBridge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventBridgeSink {
+    pub aws_account_id: String,
+    pub aws_region: String,
+    /// Partner event source created by Auth0. Read-only.
+    #[serde(skip_serializing)]
+    pub aws_partner_event_source: Option<String>,
+}
+
+/// Sink configuration for Azure Event Grid.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventGridSink {
+    pub azure_subscription_id: String,
+    pub azure_resource_group: String,
+    pub azure_region: String,
+    /// Partner topic created by Auth0. Read-only.
+    #[serde(skip_serializing)]
+    pub azure_partner_topic: Option<String>,
+}
+
+/// Datadog site to deliver events to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatadogRegion {
+    Us,
+    Eu,
+    Us3,
+    Us5,
+}
+
+/// Sink configuration for Datadog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatadogSink {
+    pub datadog_region: DatadogRegion,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub datadog_api_key: Option<SecretString>,
+}
+
+/// Sink configuration for Splunk HTTP Event Collector.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplunkSink {
+    pub splunk_domain: String,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub splunk_token: Option<SecretString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splunk_port: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splunk_secure: Option<bool>,
+}
+
+/// Sink configuration for Sumo Logic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SumoSink {
+    pub sumo_source_address: String,
+}
+
+/// Sink configuration for Segment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentSink {
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub segment_write_key: Option<SecretString>,
+}
+
+/// Mixpanel data residency region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MixpanelRegion {
+    Us,
+    Eu,
+}
+
+/// Sink configuration for Mixpanel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MixpanelSink {
+    pub mixpanel_region: MixpanelRegion,
+    pub mixpanel_project_id: String,
+    pub mixpanel_service_account_username: String,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mixpanel_service_account_password: Option<SecretString>,
+}
+
+/// Where a log stream delivers events, keyed by the stream `type`.
+///
+/// Auth0 may omit secrets when a stream is read back, so secret fields are optional and
+/// `None` in that case. Stream types not modelled here deserialize as
+/// [`LogStreamSink::Other`] with their raw configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "sink", rename_all = "lowercase")]
+pub enum LogStreamSink {
+    Http(HttpSink),
+    Eventbridge(EventBridgeSink),
+    Eventgrid(EventGridSink),
+    Datadog(DatadogSink),
+    Splunk(SplunkSink),
+    Sumo(SumoSink),
+    Segment(SegmentSink),
+    Mixpanel(MixpanelSink),
+    #[serde(untagged)]
+    Other {
+        #[serde(rename = "type")]
+        stream_type: String,
+        sink: serde_json::Value,
+    },
+}
+
+/// Restricts a log stream to one category of events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogStreamFilter {
+    /// Filter kind. Auth0 currently only supports `category`.
+    #[serde(rename = "type")]
+    pub filter_type: String,
+    pub name: LogEventCategory,
+}
+
+impl LogStreamFilter {
+    /// Deliver events in the given category.
+    pub fn category(name: LogEventCategory) -> Self {
+        Self {
+            filter_type: "category".to_string(),
+            name,
+        }
+    }
+}
+
+/// User profile field that can be masked or hashed before delivery.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiLogField {
+    FirstName,
+    LastName,
+    Username,
+    Email,
+    Phone,
+    Address,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// How PII fields are obfuscated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiMethod {
+    Mask,
+    Hash,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// PII obfuscation applied to events before they leave Auth0.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PiiConfig {
+    pub log_fields: Vec<PiiLogField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<PiiMethod>,
+    /// Hashing algorithm, e.g. `xxhash`, used when `method` is `hash`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<String>,
+}
+
+/// Represents an Auth0 log stream.
+///
+/// See the [Auth0 Log Streams documentation](https://auth0.com/docs/customize/log-streams)
+/// for detailed information about stream types.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LogStream {
+    pub id: String,
+    pub name: Option<String>,
+    pub status: Option<LogStreamStatus>,
+    #[serde(flatten)]
+    pub sink: LogStreamSink,
+    /// Categories delivered by the stream. Empty means all events.
+    #[serde(default)]
+    pub filters: Vec<LogStreamFilter>,
+    #[serde(rename = "isPriority")]
+    pub is_priority: Option<bool>,
+    pub pii_config: Option<PiiConfig>,
+}
+
+/// Request payload for creating a log stream.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateLogStreamRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub sink: LogStreamSink,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<LogStreamFilter>>,
+    #[serde(rename = "isPriority", skip_serializing_if = "Option::is_none")]
+    pub is_priority: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pii_config: Option<PiiConfig>,
+}
+
+impl CreateLogStreamRequest {
+    /// Create a stream delivering all events to the given sink.
+    pub fn new(name: impl Into<String>, sink: LogStreamSink) -> Self {
+        Self {
+            name: Some(name.into()),
+            sink,
+            filters: None,
+            is_priority: None,
+            pii_config: None,
+        }
+    }
+}
+
+/// Request payload for updating a log stream.
+///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field, e.g. `filters` to deliver all events again or `pii_config` to stop
+/// obfuscating. The stream type cannot be changed, so only the configuration inside
+/// `sink` is sent.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateLogStreamRequest {
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub status: Patch<LogStreamStatus>,
+    #[serde(
+        serialize_with = "serialize_sink_config",
+        skip_serializing_if = "Patch::is_unchanged"
+    )]
+    pub sink: Patch<LogStreamSink>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub filters: Patch<Vec<LogStreamFilter>>,
+    #[serde(rename = "isPriority", skip_serializing_if = "Patch::is_unchanged")]
+    pub is_priority: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub pii_config: Patch<PiiConfig>,
+}
+
+fn serialize_sink_config<S: Serializer>(
+    sink: &Patch<LogStreamSink>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let config = match sink {
+        Patch::Value(sink) => serde_json::to_value(sink)
+            .map_err(serde::ser::Error::custom)?
+            .get_mut("sink")
+            .map(serde_json::Value::take),
+        Patch::Unchanged | Patch::Null => None,
+    };
+    config.serialize(serializer)
+}
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use super::Timestamp;
+use super::query::{DateRange, and_query};
+
 /// Represents an Auth0 log entry.
 ///
 /// Log entries contain information about authentication and management API events,
@@ -13,7 +16,7 @@ pub struct LogEvent {
     #[serde(rename = "type")]
     pub event_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date: Option<String>,
+    pub date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -97,6 +100,23 @@ pub struct ListLogsParams {
     pub take: Option<u32>,
 }
 
+impl ListLogsParams {
+    /// Restrict results to events whose `date` falls within the given range.
+    ///
+    /// The range is combined with any existing `q` using `AND`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let params = ListLogsParams::default()
+    ///     .date_range(DateRange::new().from(yesterday).to(now));
+    /// ```
+    pub fn date_range(mut self, range: DateRange) -> Self {
+        self.q = and_query(self.q.take(), range.to_query("date"));
+        self
+    }
+}
+
 /// Paginated response for log list operations.
 ///
 /// Returned when `include_totals` is set to `true` in list parameters.
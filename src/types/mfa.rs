@@ -0,0 +1,177 @@
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+
+use super::{Timestamp, serialize_secret};
+
+/// Status of a Guardian MFA enrollment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnrollmentStatus {
+    /// Enrollment has been started but not completed.
+    Pending,
+    /// Enrollment is complete and can be used to authenticate.
+    Confirmed,
+}
+
+/// A user's Guardian MFA enrollment.
+///
+/// See the [Auth0 MFA documentation](https://auth0.com/docs/secure/multi-factor-authentication)
+/// for detailed information about enrollments.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Enrollment {
+    pub id: String,
+    pub status: Option<EnrollmentStatus>,
+    /// Enrollment type, e.g. `authenticator`, `sms` or `push`.
+    #[serde(rename = "type")]
+    pub enrollment_type: Option<String>,
+    pub name: Option<String>,
+    pub identifier: Option<String>,
+    pub phone_number: Option<String>,
+    pub auth_method: Option<String>,
+    pub enrolled_at: Option<Timestamp>,
+    pub last_auth: Option<Timestamp>,
+}
+
+/// Delivery method preferred for a phone authentication method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PhoneDeliveryMethod {
+    Sms,
+    Voice,
+}
+
+/// Fields shared by every authentication method.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuthenticationMethodInfo {
+    pub id: String,
+    pub name: Option<String>,
+    pub confirmed: Option<bool>,
+    pub created_at: Option<Timestamp>,
+    pub enrolled_at: Option<Timestamp>,
+    pub last_auth_at: Option<Timestamp>,
+}
+
+/// An authentication method enrolled by a user, keyed by its `type`.
+///
+/// Types not modelled here deserialize as [`AuthenticationMethod::Other`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum AuthenticationMethod {
+    /// One-time password from an authenticator app.
+    Totp {
+        #[serde(flatten)]
+        info: AuthenticationMethodInfo,
+    },
+    /// SMS or voice codes sent to a phone number.
+    Phone {
+        #[serde(flatten)]
+        info: AuthenticationMethodInfo,
+        phone_number: Option<String>,
+        preferred_authentication_method: Option<PhoneDeliveryMethod>,
+    },
+    /// Codes sent to an email address.
+    Email {
+        #[serde(flatten)]
+        info: AuthenticationMethodInfo,
+        email: Option<String>,
+    },
+    /// A roaming WebAuthn authenticator such as a security key.
+    WebauthnRoaming {
+        #[serde(flatten)]
+        info: AuthenticationMethodInfo,
+        key_id: Option<String>,
+        public_key: Option<String>,
+        relying_party_identifier: Option<String>,
+    },
+    /// Push notifications to the Guardian app.
+    Push {
+        #[serde(flatten)]
+        info: AuthenticationMethodInfo,
+    },
+    /// A single-use recovery code.
+    RecoveryCode {
+        #[serde(flatten)]
+        info: AuthenticationMethodInfo,
+    },
+    /// Any other authentication method type, such as `webauthn-platform` or `guardian`.
+    #[serde(untagged)]
+    Other {
+        id: String,
+        #[serde(rename = "type")]
+        kind: String,
+        /// Remaining fields, as returned by the API.
+        #[serde(flatten)]
+        extra: serde_json::Map<String, serde_json::Value>,
+    },
+}
+
+impl AuthenticationMethod {
+    /// Fields shared by all known method types, or `None` for [`AuthenticationMethod::Other`].
+    pub fn info(&self) -> Option<&AuthenticationMethodInfo> {
+        match self {
+            Self::Totp { info }
+            | Self::Phone { info, .. }
+            | Self::Email { info, .. }
+            | Self::WebauthnRoaming { info, .. }
+            | Self::Push { info }
+            | Self::RecoveryCode { info } => Some(info),
+            Self::Other { .. } => None,
+        }
+    }
+
+    /// The authentication method ID, usable with
+    /// [`UsersApi::delete_authentication_method`](crate::api::users::UsersApi::delete_authentication_method).
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Totp { info }
+            | Self::Phone { info, .. }
+            | Self::Email { info, .. }
+            | Self::WebauthnRoaming { info, .. }
+            | Self::Push { info }
+            | Self::RecoveryCode { info } => &info.id,
+            Self::Other { id, .. } => id,
+        }
+    }
+}
+
+/// Request payload for creating an authentication method for a user.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum CreateAuthenticationMethodRequest {
+    /// Enroll an authenticator app with a known TOTP secret.
+    Totp {
+        #[serde(serialize_with = "serialize_secret")]
+        totp_secret: SecretString,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    /// Enroll a phone number.
+    Phone {
+        phone_number: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        preferred_authentication_method: Option<PhoneDeliveryMethod>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    /// Enroll an email address.
+    Email {
+        email: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    /// Enroll a roaming WebAuthn authenticator.
+    WebauthnRoaming {
+        key_id: String,
+        public_key: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        relying_party_identifier: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+}
+
+/// Recovery code generated for a user.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecoveryCode {
+    pub recovery_code: SecretString,
+}
@@ -5,18 +5,79 @@ pub mod query;
 #[cfg(feature = "users")]
 pub mod users;
 
+#[cfg(feature = "users")]
+pub mod mfa;
+
+#[cfg(feature = "users")]
+pub mod user_blocks;
+
+#[cfg(feature = "users")]
+pub mod sessions;
+
+#[cfg(feature = "users")]
+pub mod refresh_tokens;
+
+#[cfg(feature = "device_credentials")]
+pub mod device_credentials;
+
+#[cfg(feature = "grants")]
+pub mod grants;
+
 #[cfg(feature = "clients")]
 pub mod clients;
 
 #[cfg(feature = "connections")]
 pub mod connections;
 
+#[cfg(feature = "connections")]
+pub mod connection_options;
+
 #[cfg(feature = "logs")]
 pub mod logs;
 
+#[cfg(feature = "actions")]
+pub mod actions;
+
+#[cfg(feature = "rules")]
+pub mod rules;
+
+#[cfg(feature = "log_streams")]
+pub mod log_streams;
+
+#[cfg(feature = "tenants")]
+pub mod tenants;
+
+#[cfg(feature = "emails")]
+pub mod emails;
+
+#[cfg(feature = "branding")]
+pub mod branding;
+
+#[cfg(feature = "branding")]
+pub mod prompts;
+
+#[cfg(feature = "custom_domains")]
+pub mod custom_domains;
+
+#[cfg(feature = "guardian")]
+pub mod guardian;
+
+#[cfg(feature = "attack_protection")]
+pub mod attack_protection;
+
+#[cfg(feature = "keys")]
+pub mod keys;
+
+#[cfg(feature = "hooks")]
+pub mod hooks;
+
+#[cfg(any(feature = "users", feature = "clients", feature = "connections"))]
+pub mod builder;
 pub mod common;
+pub mod patch;
 
 pub use common::*;
 pub use enums::*;
-pub use ids::{ClientId, ConnectionId, UserId};
-pub use query::{Page, PerPage, SearchEngine, SortDirection, SortSpec};
+pub use ids::{ActionId, ClientId, ConnectionId, RuleId, UserId};
+pub use patch::Patch;
+pub use query::{DateRange, Page, PerPage, SearchEngine, SortDirection, SortSpec};
@@ -0,0 +1,106 @@
+use serde::{Serialize, Serializer};
+
+/// Tri-state field value for update (PATCH) requests.
+///
+/// `Option<T>` cannot distinguish "leave this field alone" from "clear this field".
+/// `Patch<T>` makes that explicit: [`Patch::Unchanged`] omits the field from the request
+/// body, [`Patch::Null`] sends `null` to clear it, and [`Patch::Value`] sets it.
+///
+/// # Examples
+///
+/// ```ignore
+/// let update = UpdateUserRequest {
+///     name: Patch::Value("Jane Doe".to_string()),
+///     picture: Patch::Null,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Patch<T> {
+    /// Leave the field as it is (omitted from the request body).
+    #[default]
+    Unchanged,
+    /// Clear the field (sent as `null`).
+    Null,
+    /// Set the field to a new value.
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    /// Whether the field is left unchanged.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Patch::Unchanged)
+    }
+
+    /// Whether the field is explicitly cleared.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Patch::Null)
+    }
+
+    /// Get a reference to the new value, if one is set.
+    pub fn as_value(&self) -> Option<&T> {
+        match self {
+            Patch::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Map the contained value, preserving `Unchanged` and `Null`.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Patch<U> {
+        match self {
+            Patch::Unchanged => Patch::Unchanged,
+            Patch::Null => Patch::Null,
+            Patch::Value(v) => Patch::Value(f(v)),
+        }
+    }
+}
+
+impl<T> From<T> for Patch<T> {
+    fn from(value: T) -> Self {
+        Patch::Value(value)
+    }
+}
+
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Patch::Unchanged | Patch::Null => serializer.serialize_none(),
+            Patch::Value(v) => v.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Body {
+        #[serde(skip_serializing_if = "Patch::is_unchanged")]
+        a: Patch<String>,
+        #[serde(skip_serializing_if = "Patch::is_unchanged")]
+        b: Patch<String>,
+        #[serde(skip_serializing_if = "Patch::is_unchanged")]
+        c: Patch<String>,
+    }
+
+    #[test]
+    fn test_patch_serialization() {
+        let body = Body {
+            a: Patch::Unchanged,
+            b: Patch::Null,
+            c: "x".to_string().into(),
+        };
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            serde_json::json!({ "b": null, "c": "x" })
+        );
+    }
+
+    #[test]
+    fn test_patch_helpers() {
+        assert!(Patch::<u8>::default().is_unchanged());
+        assert!(Patch::<u8>::Null.is_null());
+        assert_eq!(Patch::Value(1).map(|v| v + 1).as_value(), Some(&2));
+    }
+}
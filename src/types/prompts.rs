@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Which Universal Login experience the tenant uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UniversalLoginExperience {
+    New,
+    Classic,
+}
+
+/// Tenant-wide prompt settings.
+///
+/// Used both as the response and the update payload; fields left as `None` are not sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromptSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universal_login_experience: Option<UniversalLoginExperience>,
+    /// Ask for the identifier before the password.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier_first: Option<bool>,
+    /// Offer biometrics as the first login factor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webauthn_platform_first_factor: Option<bool>,
+}
+
+/// Universal Login prompt whose text can be customized.
+///
+/// Prompts not modelled here are kept as [`PromptName::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PromptName {
+    Login,
+    LoginId,
+    LoginPassword,
+    LoginPasswordless,
+    LoginEmailVerification,
+    Signup,
+    SignupId,
+    SignupPassword,
+    ResetPassword,
+    Consent,
+    Logout,
+    Mfa,
+    MfaPush,
+    MfaOtp,
+    MfaVoice,
+    MfaPhone,
+    MfaWebauthn,
+    MfaSms,
+    MfaEmail,
+    MfaRecoveryCode,
+    Status,
+    DeviceFlow,
+    EmailVerification,
+    EmailOtpChallenge,
+    Organizations,
+    Invitation,
+    Common,
+    Passkeys,
+    Captcha,
+    BruteForceProtection,
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl PromptName {
+    /// The prompt name as used in API paths.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Login => "login",
+            Self::LoginId => "login-id",
+            Self::LoginPassword => "login-password",
+            Self::LoginPasswordless => "login-passwordless",
+            Self::LoginEmailVerification => "login-email-verification",
+            Self::Signup => "signup",
+            Self::SignupId => "signup-id",
+            Self::SignupPassword => "signup-password",
+            Self::ResetPassword => "reset-password",
+            Self::Consent => "consent",
+            Self::Logout => "logout",
+            Self::Mfa => "mfa",
+            Self::MfaPush => "mfa-push",
+            Self::MfaOtp => "mfa-otp",
+            Self::MfaVoice => "mfa-voice",
+            Self::MfaPhone => "mfa-phone",
+            Self::MfaWebauthn => "mfa-webauthn",
+            Self::MfaSms => "mfa-sms",
+            Self::MfaEmail => "mfa-email",
+            Self::MfaRecoveryCode => "mfa-recovery-code",
+            Self::Status => "status",
+            Self::DeviceFlow => "device-flow",
+            Self::EmailVerification => "email-verification",
+            Self::EmailOtpChallenge => "email-otp-challenge",
+            Self::Organizations => "organizations",
+            Self::Invitation => "invitation",
+            Self::Common => "common",
+            Self::Passkeys => "passkeys",
+            Self::Captcha => "captcha",
+            Self::BruteForceProtection => "brute-force-protection",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+/// Language of prompt custom text.
+///
+/// Languages not modelled here are kept as [`PromptLanguage::Other`] with their tag.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PromptLanguage {
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "es")]
+    Spanish,
+    #[serde(rename = "fr")]
+    French,
+    #[serde(rename = "fr-CA")]
+    FrenchCanada,
+    #[serde(rename = "de")]
+    German,
+    #[serde(rename = "it")]
+    Italian,
+    #[serde(rename = "nl")]
+    Dutch,
+    #[serde(rename = "pt")]
+    Portuguese,
+    #[serde(rename = "pt-BR")]
+    PortugueseBrazil,
+    #[serde(rename = "sv")]
+    Swedish,
+    #[serde(rename = "da")]
+    Danish,
+    #[serde(rename = "nb")]
+    NorwegianBokmal,
+    #[serde(rename = "fi")]
+    Finnish,
+    #[serde(rename = "pl")]
+    Polish,
+    #[serde(rename = "cs")]
+    Czech,
+    #[serde(rename = "ru")]
+    Russian,
+    #[serde(rename = "tr")]
+    Turkish,
+    #[serde(rename = "ar")]
+    Arabic,
+    #[serde(rename = "he")]
+    Hebrew,
+    #[serde(rename = "hi")]
+    Hindi,
+    #[serde(rename = "ja")]
+    Japanese,
+    #[serde(rename = "ko")]
+    Korean,
+    #[serde(rename = "zh-CN")]
+    ChineseSimplified,
+    #[serde(rename = "zh-TW")]
+    ChineseTraditional,
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl PromptLanguage {
+    /// The language tag as used in API paths.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es",
+            Self::French => "fr",
+            Self::FrenchCanada => "fr-CA",
+            Self::German => "de",
+            Self::Italian => "it",
+            Self::Dutch => "nl",
+            Self::Portuguese => "pt",
+            Self::PortugueseBrazil => "pt-BR",
+            Self::Swedish => "sv",
+            Self::Danish => "da",
+            Self::NorwegianBokmal => "nb",
+            Self::Finnish => "fi",
+            Self::Polish => "pl",
+            Self::Czech => "cs",
+            Self::Russian => "ru",
+            Self::Turkish => "tr",
+            Self::Arabic => "ar",
+            Self::Hebrew => "he",
+            Self::Hindi => "hi",
+            Self::Japanese => "ja",
+            Self::Korean => "ko",
+            Self::ChineseSimplified => "zh-CN",
+            Self::ChineseTraditional => "zh-TW",
+            Self::Other(tag) => tag,
+        }
+    }
+}
+
+/// Custom text for one prompt and language, keyed by screen name and then text key.
+///
+/// For example `custom_text["login"]["title"]` is the title of the `login` screen.
+pub type PromptCustomText = HashMap<String, HashMap<String, String>>;
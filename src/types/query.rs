@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::common::{Timestamp, format_timestamp};
+
 /// Direction for sorting query results.
 ///
 /// Used in conjunction with SortSpec to specify sort order.
@@ -191,6 +193,57 @@ impl std::fmt::Display for SearchEngine {
     }
 }
 
+/// Inclusive date range for Lucene search queries.
+///
+/// Either bound may be left open. Used to build `q` clauses such as
+/// `date:[2023-01-01T00:00:00.000Z TO *]` for logs and user searches.
+///
+/// # Examples
+///
+/// ```ignore
+/// let range = DateRange::new().from(since);
+/// assert_eq!(range.to_query("date"), "date:[2023-01-01T00:00:00.000Z TO *]");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DateRange {
+    from: Option<Timestamp>,
+    to: Option<Timestamp>,
+}
+
+impl DateRange {
+    /// Create an unbounded date range.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the inclusive lower bound.
+    pub fn from(mut self, from: impl Into<Timestamp>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Set the inclusive upper bound.
+    pub fn to(mut self, to: impl Into<Timestamp>) -> Self {
+        self.to = Some(to.into());
+        self
+    }
+
+    /// Render the range as a Lucene clause on the given field.
+    pub fn to_query(&self, field: &str) -> String {
+        let bound = |ts: &Option<Timestamp>| ts.as_ref().map_or("*".to_string(), format_timestamp);
+        format!("{}:[{} TO {}]", field, bound(&self.from), bound(&self.to))
+    }
+}
+
+/// Append a Lucene clause to an existing query with `AND`.
+#[cfg(any(feature = "users", feature = "logs"))]
+pub(crate) fn and_query(q: Option<String>, clause: String) -> Option<String> {
+    match q {
+        Some(existing) if !existing.is_empty() => Some(format!("({}) AND {}", existing, clause)),
+        _ => Some(clause),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,4 +275,41 @@ mod tests {
         assert_eq!(SearchEngine::V3.as_str(), "v3");
         assert_eq!(SearchEngine::default(), SearchEngine::V3);
     }
+
+    #[cfg(not(feature = "chrono"))]
+    #[test]
+    fn test_date_range() {
+        assert_eq!(DateRange::new().to_query("date"), "date:[* TO *]");
+
+        let range = DateRange::new()
+            .from("2023-01-01T00:00:00.000Z")
+            .to("2023-02-01T00:00:00.000Z");
+        assert_eq!(
+            range.to_query("date"),
+            "date:[2023-01-01T00:00:00.000Z TO 2023-02-01T00:00:00.000Z]"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_date_range() {
+        use chrono::TimeZone;
+
+        let from = chrono::Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let range = DateRange::new().from(from);
+        assert_eq!(
+            range.to_query("created_at"),
+            "created_at:[2023-01-01T00:00:00.000Z TO *]"
+        );
+    }
+
+    #[cfg(any(feature = "users", feature = "logs"))]
+    #[test]
+    fn test_and_query() {
+        assert_eq!(and_query(None, "a:1".into()), Some("a:1".to_string()));
+        assert_eq!(
+            and_query(Some("b:2".into()), "a:1".into()),
+            Some("(b:2) AND a:1".to_string())
+        );
+    }
 }
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+
+use super::sessions::SessionDevice;
+use super::{ClientId, Timestamp, UserId};
+
+/// An API a refresh token can be exchanged for.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefreshTokenResourceServer {
+    pub audience: String,
+    /// Space-separated scopes granted for the audience.
+    pub scopes: Option<String>,
+}
+
+/// Metadata about an issued refresh token. The token value itself is never returned.
+///
+/// See the [Auth0 Refresh Tokens documentation](https://auth0.com/docs/secure/tokens/refresh-tokens)
+/// for detailed information about rotation and expiration.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefreshToken {
+    pub id: String,
+    pub user_id: Option<UserId>,
+    pub client_id: Option<ClientId>,
+    pub session_id: Option<String>,
+    pub created_at: Option<Timestamp>,
+    pub idle_expires_at: Option<Timestamp>,
+    pub expires_at: Option<Timestamp>,
+    pub last_exchanged_at: Option<Timestamp>,
+    pub device: Option<SessionDevice>,
+    /// Whether the token is a rotating refresh token.
+    #[serde(default)]
+    pub rotating: bool,
+    #[serde(default)]
+    pub resource_servers: Vec<RefreshTokenResourceServer>,
+}
+
+/// Page of a user's refresh tokens.
+///
+/// Uses checkpoint pagination: pass `next` as `from` to fetch the following page.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefreshTokensPage {
+    pub tokens: Vec<RefreshToken>,
+    /// Checkpoint for the next page, absent on the last page.
+    pub next: Option<String>,
+}
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Patch, RuleId};
+
+/// Pipeline stage a rule runs in.
+///
+/// Only `login_success` is still executed by Auth0; the other stages are legacy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleStage {
+    LoginSuccess,
+    LoginFailure,
+    PreAuthorize,
+    UserRegistration,
+    UserBlocked,
+}
+
+/// Represents an Auth0 Rule.
+///
+/// See the [Auth0 Rules documentation](https://auth0.com/docs/customize/rules)
+/// for detailed information about rules.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rule {
+    pub id: RuleId,
+    pub name: Option<String>,
+    pub script: Option<String>,
+    /// Execution order relative to other rules; lower runs first.
+    pub order: Option<i64>,
+    pub enabled: Option<bool>,
+    pub stage: Option<RuleStage>,
+}
+
+/// Request payload for creating a new rule.
+///
+/// # Examples
+///
+/// ```ignore
+/// let script = "function (user, context, callback) { callback(null, user, context); }";
+/// let request = CreateRuleRequest {
+///     order: Some(2),
+///     ..CreateRuleRequest::new("add-country", script)
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateRuleRequest {
+    pub name: String,
+    pub script: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Defaults to `login_success` when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<RuleStage>,
+}
+
+impl CreateRuleRequest {
+    /// Create a rule with the given name and script, leaving order, enabled and stage unset.
+    pub fn new(name: impl Into<String>, script: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            script: script.into(),
+            order: None,
+            enabled: None,
+            stage: None,
+        }
+    }
+}
+
+/// Request payload for updating an existing rule.
+///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateRuleRequest {
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub script: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub order: Patch<i64>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub enabled: Patch<bool>,
+}
+
+/// Query parameters for listing rules.
+///
+/// See the [Auth0 Get Rules documentation](https://auth0.com/docs/api/management/v2#!/Rules/get_rules)
+/// for detailed information about available filters.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListRulesParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<bool>,
+}
+
+/// Paginated response for rule list operations.
+///
+/// Returned when `include_totals` is set to `true` in list parameters.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RulesPage {
+    /// List of rules in this page.
+    pub rules: Vec<Rule>,
+    /// Starting index of this page (zero-based).
+    pub start: u32,
+    /// Maximum number of results per page.
+    pub limit: u32,
+    /// Total number of rules matching the query.
+    pub total: u32,
+}
+
+/// Key of a rules config variable. Values are write-only and never returned.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RulesConfigKey {
+    pub key: String,
+}
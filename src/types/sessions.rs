@@ -0,0 +1,68 @@
+use serde::Deserialize;
+
+use super::{ClientId, Timestamp, UserId};
+
+/// Device information recorded for a session or refresh token.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SessionDevice {
+    pub initial_user_agent: Option<String>,
+    pub initial_ip: Option<String>,
+    pub initial_asn: Option<String>,
+    pub last_user_agent: Option<String>,
+    pub last_ip: Option<String>,
+    pub last_asn: Option<String>,
+}
+
+/// A client the session has been used with.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionClient {
+    pub client_id: ClientId,
+}
+
+/// An authentication method used during a session.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionAuthenticationMethod {
+    /// Method name, e.g. `pwd`, `federated` or `mfa`.
+    pub name: String,
+    pub timestamp: Option<Timestamp>,
+    /// Second-factor type for `mfa` methods.
+    #[serde(rename = "type")]
+    pub method_type: Option<String>,
+}
+
+/// How a session was authenticated.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SessionAuthentication {
+    #[serde(default)]
+    pub methods: Vec<SessionAuthenticationMethod>,
+}
+
+/// Represents an Auth0 login session.
+///
+/// See the [Auth0 Sessions documentation](https://auth0.com/docs/manage-users/sessions)
+/// for detailed information about session lifetimes.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Session {
+    pub id: String,
+    pub user_id: Option<UserId>,
+    pub created_at: Option<Timestamp>,
+    pub updated_at: Option<Timestamp>,
+    pub authenticated_at: Option<Timestamp>,
+    pub last_interacted_at: Option<Timestamp>,
+    pub idle_expires_at: Option<Timestamp>,
+    pub expires_at: Option<Timestamp>,
+    pub device: Option<SessionDevice>,
+    #[serde(default)]
+    pub clients: Vec<SessionClient>,
+    pub authentication: Option<SessionAuthentication>,
+}
+
+/// Page of a user's sessions.
+///
+/// Uses checkpoint pagination: pass `next` as `from` to fetch the following page.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionsPage {
+    pub sessions: Vec<Session>,
+    /// Checkpoint for the next page, absent on the last page.
+    pub next: Option<String>,
+}
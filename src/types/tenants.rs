@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+
+use super::Patch;
+
+/// Tenant feature flags.
+///
+/// Only commonly changed flags are modelled; the rest are kept in `extra` so that a
+/// read-modify-write round trip does not reset them. Flags left as `None` are not sent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TenantFlags {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_client_connections: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_apis_section: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_pipeline2: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_dynamic_client_registration: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_custom_domain_in_emails: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_public_signup_user_exists_error: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_sso: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_legacy_delegation_grant_types: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_legacy_ro_grant_types: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_legacy_tokeninfo_endpoint: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_legacy_profile: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_idtoken_api2: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_clickjack_protection_headers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_management_api_sms_obfuscation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_fields_map_fix: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_disclose_enterprise_connections: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoke_refresh_token_grant: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dashboard_log_streams_next: Option<bool>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Whether the Auth0 session cookie outlives the browser session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SessionCookieMode {
+    Persistent,
+    NonPersistent,
+}
+
+/// Session cookie settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionCookie {
+    pub mode: SessionCookieMode,
+}
+
+/// Character set used to generate device flow user codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeviceFlowCharset {
+    /// Consonants only, e.g. `BDFG-HJKL`.
+    Base20,
+    /// Digits only.
+    Digits,
+}
+
+/// Device authorization flow settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceFlow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charset: Option<DeviceFlowCharset>,
+    /// Mask applied to user codes, e.g. `****-****`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask: Option<String>,
+}
+
+/// Page shown when an error occurs during authentication.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorPage {
+    /// Custom page HTML, rendered with Liquid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_log_link: Option<bool>,
+    /// Redirect to this URL instead of rendering `html`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Tenant-wide settings.
+///
+/// Lifetimes are in hours.
+///
+/// See the [Auth0 Tenant Settings documentation](https://auth0.com/docs/get-started/tenant-settings)
+/// for detailed information about each setting.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TenantSettings {
+    pub friendly_name: Option<String>,
+    pub picture_url: Option<String>,
+    pub support_email: Option<String>,
+    pub support_url: Option<String>,
+    pub default_audience: Option<String>,
+    pub default_directory: Option<String>,
+    pub default_redirection_uri: Option<String>,
+    #[serde(default)]
+    pub allowed_logout_urls: Vec<String>,
+    #[serde(default)]
+    pub enabled_locales: Vec<String>,
+    pub session_lifetime: Option<f64>,
+    pub idle_session_lifetime: Option<f64>,
+    pub sandbox_version: Option<String>,
+    pub flags: Option<TenantFlags>,
+    pub session_cookie: Option<SessionCookie>,
+    pub device_flow: Option<DeviceFlow>,
+    pub error_page: Option<ErrorPage>,
+}
+
+/// Request payload for updating tenant settings.
+///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field. `flags` is merged into the existing flags by Auth0, so only the flags
+/// that are set need to be provided.
+///
+/// # Examples
+///
+/// ```ignore
+/// let request = UpdateTenantSettingsRequest {
+///     session_lifetime: Patch::Value(72.0),
+///     flags: Patch::Value(TenantFlags {
+///         enable_client_connections: Some(false),
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateTenantSettingsRequest {
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub friendly_name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub picture_url: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub support_email: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub support_url: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub default_audience: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub default_directory: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub default_redirection_uri: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub allowed_logout_urls: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub enabled_locales: Patch<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub session_lifetime: Patch<f64>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub idle_session_lifetime: Patch<f64>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub sandbox_version: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub flags: Patch<TenantFlags>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub session_cookie: Patch<SessionCookie>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub device_flow: Patch<DeviceFlow>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub error_page: Patch<ErrorPage>,
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+use super::Timestamp;
+
+/// Brute-force protection blocks for a user or identifier.
+///
+/// See the [Auth0 Brute-Force Protection documentation](https://auth0.com/docs/secure/attack-protection/brute-force-protection)
+/// for detailed information about blocks.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UserBlocks {
+    #[serde(default)]
+    pub blocked_for: Vec<BlockedEntry>,
+}
+
+impl UserBlocks {
+    /// Whether any block is currently in place.
+    pub fn is_blocked(&self) -> bool {
+        !self.blocked_for.is_empty()
+    }
+}
+
+/// A single brute-force block, tied to the identifier and the IP address it came from.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BlockedEntry {
+    /// The username, email or phone number that was blocked.
+    pub identifier: String,
+    /// The IP address the failed attempts came from.
+    pub ip: Option<String>,
+    pub connection: Option<String>,
+    pub timestamp: Option<Timestamp>,
+}
@@ -1,24 +1,36 @@
-use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
 
-use super::Metadata;
+use secrecy::SecretString;
+use serde::{Deserialize, Deserializer, Serialize, de::DeserializeOwned};
+
+use crate::error::{Auth0Error, Result};
+
+use super::builder::{
+    Missing, Provided, option_setters, patch_setters, require_changes, require_non_empty,
+};
+use super::query::{DateRange, and_query};
+use super::{ConnectionId, ConnectionStrategy, Metadata, Patch, Timestamp, serialize_secret};
 
 /// Represents an Auth0 user.
 ///
+/// The metadata type parameters default to [`Metadata`]. Use [`TypedUser`] to bind
+/// `app_metadata` and `user_metadata` to your own structs instead.
+///
 /// See the [Auth0 User Profile documentation](https://auth0.com/docs/users/manage-users#user-profiles)
 /// for detailed information about user properties.
 #[derive(Debug, Clone, Deserialize)]
-pub struct User {
+pub struct User<A = Metadata, U = Metadata> {
     pub user_id: String,
     pub email: Option<String>,
     pub email_verified: Option<bool>,
     pub username: Option<String>,
     pub phone_number: Option<String>,
     pub phone_verified: Option<bool>,
-    pub created_at: Option<String>,
-    pub updated_at: Option<String>,
+    pub created_at: Option<Timestamp>,
+    pub updated_at: Option<Timestamp>,
     pub identities: Option<Vec<Identity>>,
-    pub app_metadata: Option<Metadata>,
-    pub user_metadata: Option<Metadata>,
+    pub app_metadata: Option<A>,
+    pub user_metadata: Option<U>,
     pub picture: Option<String>,
     pub name: Option<String>,
     pub nickname: Option<String>,
@@ -26,20 +38,189 @@ pub struct User {
     pub family_name: Option<String>,
     pub blocked: Option<bool>,
     pub last_ip: Option<String>,
-    pub last_login: Option<String>,
+    pub last_login: Option<Timestamp>,
     pub logins_count: Option<u64>,
 }
 
+/// A user whose `app_metadata` and `user_metadata` are deserialized into typed structs.
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(Deserialize)]
+/// struct AppMeta { plan: String }
+///
+/// let user: TypedUser<AppMeta, Metadata> = client.users().get_typed(id).await?;
+/// println!("{:?}", user.app_metadata.map(|m| m.plan));
+/// ```
+pub type TypedUser<A, U> = User<A, U>;
+
+impl User {
+    /// Deserialize `app_metadata` into a typed struct.
+    ///
+    /// Returns `Ok(None)` if the user has no app metadata.
+    pub fn app_metadata_as<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        self.app_metadata
+            .as_ref()
+            .map(Metadata::to_typed)
+            .transpose()
+    }
+
+    /// Deserialize `user_metadata` into a typed struct.
+    ///
+    /// Returns `Ok(None)` if the user has no user metadata.
+    pub fn user_metadata_as<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        self.user_metadata
+            .as_ref()
+            .map(Metadata::to_typed)
+            .transpose()
+    }
+
+    /// Convert into a [`TypedUser`] by deserializing both metadata objects.
+    pub fn into_typed<A: DeserializeOwned, U: DeserializeOwned>(self) -> Result<TypedUser<A, U>> {
+        let app_metadata = self.app_metadata_as()?;
+        let user_metadata = self.user_metadata_as()?;
+
+        Ok(User {
+            user_id: self.user_id,
+            email: self.email,
+            email_verified: self.email_verified,
+            username: self.username,
+            phone_number: self.phone_number,
+            phone_verified: self.phone_verified,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            identities: self.identities,
+            app_metadata,
+            user_metadata,
+            picture: self.picture,
+            name: self.name,
+            nickname: self.nickname,
+            given_name: self.given_name,
+            family_name: self.family_name,
+            blocked: self.blocked,
+            last_ip: self.last_ip,
+            last_login: self.last_login,
+            logins_count: self.logins_count,
+        })
+    }
+}
+
 /// Represents a user's identity (connection to an auth provider).
 ///
 /// Each user can have multiple identities linked to different providers or connections.
+/// Provider tokens are only returned to callers with the `read:user_idp_tokens` scope.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Identity {
     pub connection: String,
+    /// The user ID at the provider. Some social providers return this as a number.
+    #[serde(deserialize_with = "deserialize_identity_user_id")]
     pub user_id: String,
     pub provider: String,
     #[serde(rename = "isSocial")]
     pub is_social: bool,
+    /// Profile attributes of a linked secondary identity.
+    #[serde(rename = "profileData")]
+    pub profile_data: Option<ProfileData>,
+    pub access_token: Option<SecretString>,
+    pub access_token_secret: Option<SecretString>,
+    pub refresh_token: Option<SecretString>,
+}
+
+impl Identity {
+    /// Whether the provider's access token was included in the response.
+    pub fn has_access_token(&self) -> bool {
+        self.access_token.is_some()
+    }
+
+    /// The connection strategy for this identity's provider, if it is a known one.
+    pub fn strategy(&self) -> Option<ConnectionStrategy> {
+        serde_json::from_value(serde_json::Value::String(self.provider.clone())).ok()
+    }
+}
+
+/// Profile attributes stored on a linked secondary identity.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProfileData {
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub name: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub nickname: Option<String>,
+    pub username: Option<String>,
+    pub phone_number: Option<String>,
+    pub phone_verified: Option<bool>,
+    pub picture: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+fn deserialize_identity_user_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(serde_json::Number),
+    }
+
+    Ok(match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(s) => s,
+        StringOrNumber::Number(n) => n.to_string(),
+    })
+}
+
+/// Request payload for linking a secondary account to a primary user.
+///
+/// Either identify the secondary account by provider and user ID, or pass the
+/// secondary account's ID token via [`LinkIdentityRequest::token`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum LinkIdentityRequest {
+    /// Link by the secondary account's provider and user ID.
+    User {
+        provider: String,
+        user_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        connection_id: Option<ConnectionId>,
+    },
+    /// Link using the secondary account's ID token.
+    Token {
+        #[serde(serialize_with = "serialize_secret")]
+        link_with: SecretString,
+    },
+}
+
+impl LinkIdentityRequest {
+    /// Link the secondary account with the given provider and user ID.
+    ///
+    /// `user_id` is the ID at the provider, without the `provider|` prefix.
+    pub fn user(provider: impl Into<String>, user_id: impl Into<String>) -> Self {
+        Self::User {
+            provider: provider.into(),
+            user_id: user_id.into(),
+            connection_id: None,
+        }
+    }
+
+    /// Link the secondary account identified by its ID token.
+    pub fn token(id_token: impl Into<String>) -> Self {
+        Self::Token {
+            link_with: SecretString::from(id_token.into()),
+        }
+    }
+
+    /// Restrict the secondary account lookup to a specific connection.
+    ///
+    /// Has no effect on token-based requests.
+    pub fn connection_id(mut self, id: impl Into<ConnectionId>) -> Self {
+        if let Self::User { connection_id, .. } = &mut self {
+            *connection_id = Some(id.into());
+        }
+        self
+    }
 }
 
 /// Request payload for creating a new user.
@@ -58,15 +239,15 @@ pub struct Identity {
 ///
 /// See the [Auth0 Create User documentation](https://auth0.com/docs/api/management/v2#!/Users/post_users)
 /// for detailed information about user creation.
-#[derive(Debug, Clone, Default, Serialize)]
-pub struct CreateUserRequest {
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateUserRequest<A = Metadata, U = Metadata> {
     pub connection: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phone_number: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_metadata: Option<Metadata>,
+    pub user_metadata: Option<U>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blocked: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -74,7 +255,7 @@ pub struct CreateUserRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phone_verified: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub app_metadata: Option<Metadata>,
+    pub app_metadata: Option<A>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub given_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -95,59 +276,420 @@ pub struct CreateUserRequest {
     pub verify_email: Option<bool>,
 }
 
+/// A [`CreateUserRequest`] with typed `app_metadata` and `user_metadata`.
+pub type TypedCreateUserRequest<A, U> = CreateUserRequest<A, U>;
+
+impl<A, U> Default for CreateUserRequest<A, U> {
+    fn default() -> Self {
+        Self {
+            connection: String::new(),
+            email: None,
+            phone_number: None,
+            user_metadata: None,
+            blocked: None,
+            email_verified: None,
+            phone_verified: None,
+            app_metadata: None,
+            given_name: None,
+            family_name: None,
+            name: None,
+            nickname: None,
+            picture: None,
+            user_id: None,
+            password: None,
+            username: None,
+            verify_email: None,
+        }
+    }
+}
+
 /// Request payload for updating a user.
 ///
 /// # Examples
 ///
 /// ```ignore
 /// let update = UpdateUserRequest {
-///     email: Some("newemail@example.com".to_string()),
-///     email_verified: Some(true),
-///     blocked: Some(false),
+///     email: Patch::Value("newemail@example.com".to_string()),
+///     email_verified: Patch::Value(true),
+///     picture: Patch::Null,
 ///     ..Default::default()
 /// };
 /// ```
 ///
+/// Fields left as [`Patch::Unchanged`] are omitted from the request body; [`Patch::Null`]
+/// clears the field.
+///
 /// See the [Auth0 Update User documentation](https://auth0.com/docs/api/management/v2#!/Users/patch_users_by_id)
 /// for detailed information about user updates.
-#[derive(Debug, Clone, Default, Serialize)]
-pub struct UpdateUserRequest {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub blocked: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email_verified: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_verified: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_metadata: Option<Metadata>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub app_metadata: Option<Metadata>,
-    #[serde()]
-    pub given_name: Option<String>,
-    #[serde()]
-    pub family_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub nickname: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub picture: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub password: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub username: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub verify_email: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub verify_phone_number: Option<bool>,
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateUserRequest<A = Metadata, U = Metadata> {
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub blocked: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub email_verified: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub email: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub phone_number: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub phone_verified: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub user_metadata: Patch<U>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub app_metadata: Patch<A>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub given_name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub family_name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub name: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub nickname: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub picture: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub password: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub connection: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub client_id: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub username: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub verify_email: Patch<bool>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub verify_phone_number: Patch<bool>,
+}
+
+/// An [`UpdateUserRequest`] with typed `app_metadata` and `user_metadata`.
+pub type TypedUpdateUserRequest<A, U> = UpdateUserRequest<A, U>;
+
+impl<A, U> Default for UpdateUserRequest<A, U> {
+    fn default() -> Self {
+        Self {
+            blocked: Patch::Unchanged,
+            email_verified: Patch::Unchanged,
+            email: Patch::Unchanged,
+            phone_number: Patch::Unchanged,
+            phone_verified: Patch::Unchanged,
+            user_metadata: Patch::Unchanged,
+            app_metadata: Patch::Unchanged,
+            given_name: Patch::Unchanged,
+            family_name: Patch::Unchanged,
+            name: Patch::Unchanged,
+            nickname: Patch::Unchanged,
+            picture: Patch::Unchanged,
+            password: Patch::Unchanged,
+            connection: Patch::Unchanged,
+            client_id: Patch::Unchanged,
+            username: Patch::Unchanged,
+            verify_email: Patch::Unchanged,
+            verify_phone_number: Patch::Unchanged,
+        }
+    }
+}
+
+impl CreateUserRequest {
+    /// Start building a [`CreateUserRequest`].
+    ///
+    /// `connection` is required before `build()` becomes available.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let request = CreateUserRequest::builder()
+    ///     .connection("Username-Password-Authentication")
+    ///     .email("user@example.com")
+    ///     .password("SecurePassword123!")
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> CreateUserRequestBuilder<Missing> {
+        CreateUserRequestBuilder {
+            request: CreateUserRequest::default(),
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<A, U> CreateUserRequest<A, U> {
+    fn with_metadata<A2, U2>(
+        self,
+        app_metadata: Option<A2>,
+        user_metadata: Option<U2>,
+    ) -> CreateUserRequest<A2, U2> {
+        CreateUserRequest {
+            connection: self.connection,
+            email: self.email,
+            phone_number: self.phone_number,
+            user_metadata,
+            blocked: self.blocked,
+            email_verified: self.email_verified,
+            phone_verified: self.phone_verified,
+            app_metadata,
+            given_name: self.given_name,
+            family_name: self.family_name,
+            name: self.name,
+            nickname: self.nickname,
+            picture: self.picture,
+            user_id: self.user_id,
+            password: self.password,
+            username: self.username,
+            verify_email: self.verify_email,
+        }
+    }
+}
+
+/// Fluent builder for [`CreateUserRequest`].
+///
+/// The `C` parameter tracks whether `connection` has been set. Metadata setters accept
+/// any serializable type, switching the request to a [`TypedCreateUserRequest`].
+#[derive(Debug, Clone)]
+pub struct CreateUserRequestBuilder<C, A = Metadata, U = Metadata> {
+    request: CreateUserRequest<A, U>,
+    _state: PhantomData<C>,
+}
+
+impl<A, U> CreateUserRequestBuilder<Missing, A, U> {
+    /// Set the connection the user is created in.
+    pub fn connection(
+        mut self,
+        connection: impl Into<String>,
+    ) -> CreateUserRequestBuilder<Provided, A, U> {
+        self.request.connection = connection.into();
+        CreateUserRequestBuilder {
+            request: self.request,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<C, A, U> CreateUserRequestBuilder<C, A, U> {
+    option_setters! {
+        /// Set the user's email address.
+        email: String,
+        /// Set the user's phone number.
+        phone_number: String,
+        /// Set whether the user is blocked.
+        blocked: bool,
+        /// Set whether the email address is verified.
+        email_verified: bool,
+        /// Set whether the phone number is verified.
+        phone_verified: bool,
+        /// Set the user's given name.
+        given_name: String,
+        /// Set the user's family name.
+        family_name: String,
+        /// Set the user's full name.
+        name: String,
+        /// Set the user's nickname.
+        nickname: String,
+        /// Set the URL of the user's picture.
+        picture: String,
+        /// Set the user ID (without the connection prefix).
+        user_id: String,
+        /// Set the initial password.
+        password: String,
+        /// Set the username.
+        username: String,
+        /// Set whether a verification email is sent.
+        verify_email: bool,
+    }
+
+    /// Set `app_metadata`, which may be any serializable type.
+    pub fn app_metadata<A2>(self, app_metadata: A2) -> CreateUserRequestBuilder<C, A2, U> {
+        let user_metadata = self.request.user_metadata;
+        let request = CreateUserRequest {
+            user_metadata: None,
+            app_metadata: None,
+            ..self.request
+        };
+        CreateUserRequestBuilder {
+            request: request.with_metadata(Some(app_metadata), user_metadata),
+            _state: PhantomData,
+        }
+    }
+
+    /// Set `user_metadata`, which may be any serializable type.
+    pub fn user_metadata<U2>(self, user_metadata: U2) -> CreateUserRequestBuilder<C, A, U2> {
+        let app_metadata = self.request.app_metadata;
+        let request = CreateUserRequest {
+            user_metadata: None,
+            app_metadata: None,
+            ..self.request
+        };
+        CreateUserRequestBuilder {
+            request: request.with_metadata(app_metadata, Some(user_metadata)),
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<A, U> CreateUserRequestBuilder<Provided, A, U> {
+    /// Validate and build the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Configuration` if `connection` is blank, `email` is not an
+    /// email address, or `password` is empty.
+    pub fn build(self) -> Result<CreateUserRequest<A, U>> {
+        let request = self.request;
+        require_non_empty("connection", &request.connection)?;
+        if let Some(email) = &request.email {
+            validate_email(email)?;
+        }
+        if let Some(password) = &request.password {
+            require_non_empty("password", password)?;
+        }
+        Ok(request)
+    }
+}
+
+impl UpdateUserRequest {
+    /// Start building an [`UpdateUserRequest`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let request = UpdateUserRequest::builder()
+    ///     .nickname("jd")
+    ///     .clear_picture()
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> UpdateUserRequestBuilder {
+        UpdateUserRequestBuilder {
+            request: UpdateUserRequest::default(),
+        }
+    }
+}
+
+impl<A, U> UpdateUserRequest<A, U> {
+    fn with_metadata<A2, U2>(
+        self,
+        app_metadata: Patch<A2>,
+        user_metadata: Patch<U2>,
+    ) -> UpdateUserRequest<A2, U2> {
+        UpdateUserRequest {
+            blocked: self.blocked,
+            email_verified: self.email_verified,
+            email: self.email,
+            phone_number: self.phone_number,
+            phone_verified: self.phone_verified,
+            user_metadata,
+            app_metadata,
+            given_name: self.given_name,
+            family_name: self.family_name,
+            name: self.name,
+            nickname: self.nickname,
+            picture: self.picture,
+            password: self.password,
+            connection: self.connection,
+            client_id: self.client_id,
+            username: self.username,
+            verify_email: self.verify_email,
+            verify_phone_number: self.verify_phone_number,
+        }
+    }
+}
+
+/// Fluent builder for [`UpdateUserRequest`].
+///
+/// Each setter has a matching `clear_*` method that sends `null` for the field.
+#[derive(Debug, Clone)]
+pub struct UpdateUserRequestBuilder<A = Metadata, U = Metadata> {
+    request: UpdateUserRequest<A, U>,
+}
+
+impl<A, U> UpdateUserRequestBuilder<A, U> {
+    patch_setters! {
+        /// Set whether the user is blocked.
+        blocked: bool,
+        /// Set whether the email address is verified.
+        email_verified: bool,
+        /// Set the user's email address.
+        email / clear_email: String,
+        /// Set the user's phone number.
+        phone_number / clear_phone_number: String,
+        /// Set whether the phone number is verified.
+        phone_verified: bool,
+        /// Set the user's given name.
+        given_name / clear_given_name: String,
+        /// Set the user's family name.
+        family_name / clear_family_name: String,
+        /// Set the user's full name.
+        name / clear_name: String,
+        /// Set the user's nickname.
+        nickname / clear_nickname: String,
+        /// Set the URL of the user's picture.
+        picture / clear_picture: String,
+        /// Set a new password.
+        password: String,
+        /// Set the connection the change applies to.
+        connection: String,
+        /// Set the client ID used for verification emails.
+        client_id: String,
+        /// Set the username.
+        username / clear_username: String,
+        /// Set whether a verification email is sent.
+        verify_email: bool,
+        /// Set whether a verification SMS is sent.
+        verify_phone_number: bool,
+    }
+
+    /// Set `app_metadata`, which may be any serializable type.
+    pub fn app_metadata<A2>(self, app_metadata: A2) -> UpdateUserRequestBuilder<A2, U> {
+        let user_metadata = self.request.user_metadata;
+        let request = UpdateUserRequest {
+            user_metadata: Patch::Unchanged,
+            app_metadata: Patch::Unchanged,
+            ..self.request
+        };
+        UpdateUserRequestBuilder {
+            request: request.with_metadata(Patch::Value(app_metadata), user_metadata),
+        }
+    }
+
+    /// Set `user_metadata`, which may be any serializable type.
+    pub fn user_metadata<U2>(self, user_metadata: U2) -> UpdateUserRequestBuilder<A, U2> {
+        let app_metadata = self.request.app_metadata;
+        let request = UpdateUserRequest {
+            user_metadata: Patch::Unchanged,
+            app_metadata: Patch::Unchanged,
+            ..self.request
+        };
+        UpdateUserRequestBuilder {
+            request: request.with_metadata(app_metadata, Patch::Value(user_metadata)),
+        }
+    }
+}
+
+impl<A: Serialize, U: Serialize> UpdateUserRequestBuilder<A, U> {
+    /// Validate and build the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Auth0Error::Configuration` if no field is changed, `email` is not an
+    /// email address, or `password` is empty.
+    pub fn build(self) -> Result<UpdateUserRequest<A, U>> {
+        let request = self.request;
+        require_changes("user update", &request)?;
+        if let Patch::Value(email) = &request.email {
+            validate_email(email)?;
+        }
+        if let Patch::Value(password) = &request.password {
+            require_non_empty("password", password)?;
+        }
+        Ok(request)
+    }
+}
+
+fn validate_email(email: &str) -> Result<()> {
+    match email.split_once('@') {
+        Some((local, domain)) if !local.is_empty() && !domain.is_empty() => Ok(()),
+        _ => Err(Auth0Error::Configuration(format!(
+            "invalid email address '{}'",
+            email
+        ))),
+    }
 }
 
 /// Query parameters for listing users.
@@ -174,6 +716,24 @@ pub struct ListUsersParams {
     pub search_engine: Option<String>,
 }
 
+impl ListUsersParams {
+    /// Restrict results to users created within the given range.
+    ///
+    /// The range is combined with any existing `q` using `AND`.
+    pub fn created_between(mut self, range: DateRange) -> Self {
+        self.q = and_query(self.q.take(), range.to_query("created_at"));
+        self
+    }
+
+    /// Restrict results to users whose last login falls within the given range.
+    ///
+    /// The range is combined with any existing `q` using `AND`.
+    pub fn last_login_between(mut self, range: DateRange) -> Self {
+        self.q = and_query(self.q.take(), range.to_query("last_login"));
+        self
+    }
+}
+
 /// Query parameters for getting user logs.
 ///
 /// See the [Auth0 Get User Logs documentation](https://auth0.com/docs/api/management/v2/users/get-logs-by-user)
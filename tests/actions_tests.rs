@@ -0,0 +1,423 @@
+#![cfg(feature = "actions")]
+
+use std::time::Duration;
+
+use auth0_mgmt_api::{
+    ActionBindingUpdate, ActionBuildStatus, ActionDependency, ActionId, ActionSecretValue,
+    ActionTrigger, ActionTriggerId, Auth0Error, CreateActionRequest, ListActionsParams,
+    ManagementClient, Patch, PollConfig, UpdateActionRequest,
+};
+use wiremock::matchers::{bearer_token, body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+fn fast_poll() -> PollConfig {
+    PollConfig {
+        initial_interval: Duration::from_millis(5),
+        max_interval: Duration::from_millis(10),
+        multiplier: 2.0,
+        timeout: Duration::from_millis(200),
+    }
+}
+
+#[tokio::test]
+async fn test_create_action() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/actions/actions"))
+        .and(body_json(serde_json::json!({
+            "name": "add-roles",
+            "supported_triggers": [{ "id": "post-login", "version": "v3" }],
+            "code": "exports.onExecutePostLogin = async () => {};",
+            "dependencies": [{ "name": "lodash", "version": "4.17.21" }],
+            "secrets": [{ "name": "API_KEY", "value": "s3cret" }]
+        })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "act_123",
+            "name": "add-roles",
+            "supported_triggers": [{ "id": "post-login", "version": "v3" }],
+            "dependencies": [{ "name": "lodash", "version": "4.17.21" }],
+            "secrets": [{ "name": "API_KEY", "updated_at": "2023-11-15T10:30:00.000Z" }],
+            "status": "pending"
+        })))
+        .mount(&server)
+        .await;
+
+    let mut request = CreateActionRequest::new(
+        "add-roles",
+        ActionTrigger::new(ActionTriggerId::PostLogin, "v3"),
+    );
+    request.code = Some("exports.onExecutePostLogin = async () => {};".to_string());
+    request.dependencies = Some(vec![ActionDependency::new("lodash", "4.17.21")]);
+    request.secrets = Some(vec![ActionSecretValue::new("API_KEY", "s3cret")]);
+
+    let action = client
+        .actions()
+        .create(request)
+        .await
+        .expect("Failed to create action");
+
+    assert_eq!(action.id, ActionId::new("act_123"));
+    assert_eq!(action.status, Some(ActionBuildStatus::Pending));
+    assert_eq!(action.supported_triggers[0].id, ActionTriggerId::PostLogin);
+    assert_eq!(action.secrets[0].name, "API_KEY");
+}
+
+#[tokio::test]
+async fn test_update_action_clears_dependencies() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/actions/actions/act_123"))
+        .and(body_json(serde_json::json!({
+            "code": "exports.onExecutePostLogin = async () => {};",
+            "dependencies": null
+        })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "act_123",
+            "name": "add-roles",
+            "supported_triggers": [{ "id": "post-login", "version": "v3" }],
+            "status": "pending"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = UpdateActionRequest {
+        code: Patch::Value("exports.onExecutePostLogin = async () => {};".to_string()),
+        dependencies: Patch::Null,
+        ..Default::default()
+    };
+
+    let action = client
+        .actions()
+        .update(ActionId::new("act_123"), request)
+        .await
+        .expect("Failed to update action");
+
+    assert!(action.dependencies.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_actions_by_trigger() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/actions/actions"))
+        .and(query_param("triggerId", "post-login"))
+        .and(query_param("deployed", "true"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "actions": [
+                {
+                    "id": "act_123",
+                    "name": "add-roles",
+                    "supported_triggers": [{ "id": "custom-trigger", "version": "v1" }],
+                    "status": "built"
+                }
+            ],
+            "total": 1,
+            "page": 0,
+            "per_page": 50
+        })))
+        .mount(&server)
+        .await;
+
+    let params = ListActionsParams {
+        trigger_id: Some(ActionTriggerId::PostLogin),
+        deployed: Some(true),
+        ..Default::default()
+    };
+    let page = client
+        .actions()
+        .list(Some(params))
+        .await
+        .expect("Failed to list actions");
+
+    assert_eq!(page.total, Some(1));
+    assert_eq!(
+        page.actions[0].supported_triggers[0].id,
+        ActionTriggerId::Other("custom-trigger".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_deploy_and_rollback_action() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/actions/actions/act_123/deploy"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "ver_2",
+            "action_id": "act_123",
+            "number": 2,
+            "deployed": true,
+            "status": "built"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/actions/actions/act_123/versions/ver_1/deploy",
+        ))
+        .and(body_json(serde_json::json!({ "update_draft": true })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "ver_3",
+            "action_id": "act_123",
+            "number": 3,
+            "deployed": true
+        })))
+        .mount(&server)
+        .await;
+
+    let id = ActionId::new("act_123");
+    let deployed = client
+        .actions()
+        .deploy(id.clone())
+        .await
+        .expect("Failed to deploy action");
+    assert_eq!(deployed.number, Some(2));
+    assert!(deployed.deployed);
+
+    let rolled_back = client
+        .actions()
+        .rollback(id, "ver_1", true)
+        .await
+        .expect("Failed to roll back action");
+    assert_eq!(rolled_back.number, Some(3));
+}
+
+#[tokio::test]
+async fn test_list_triggers() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/actions/triggers"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "triggers": [
+                {
+                    "id": "post-login",
+                    "version": "v3",
+                    "status": "CURRENT",
+                    "runtimes": ["node18", "node22"],
+                    "default_runtime": "node22"
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let triggers = client
+        .actions()
+        .list_triggers()
+        .await
+        .expect("Failed to list triggers");
+
+    assert_eq!(triggers[0].id, ActionTriggerId::PostLogin);
+    assert_eq!(triggers[0].default_runtime.as_deref(), Some("node22"));
+}
+
+#[tokio::test]
+async fn test_update_bindings() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/actions/triggers/post-login/bindings"))
+        .and(body_json(serde_json::json!({
+            "bindings": [
+                { "ref": { "type": "action_id", "value": "act_123" } },
+                {
+                    "ref": { "type": "action_name", "value": "audit" },
+                    "display_name": "Audit"
+                }
+            ]
+        })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bindings": [
+                {
+                    "id": "bind_1",
+                    "trigger_id": "post-login",
+                    "display_name": "add-roles",
+                    "action": { "id": "act_123", "name": "add-roles" }
+                },
+                {
+                    "id": "bind_2",
+                    "trigger_id": "post-login",
+                    "display_name": "Audit",
+                    "action": { "id": "act_456", "name": "audit" }
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let mut audit = ActionBindingUpdate::action_name("audit");
+    audit.display_name = Some("Audit".to_string());
+
+    let bindings = client
+        .actions()
+        .update_bindings(
+            ActionTriggerId::PostLogin,
+            vec![ActionBindingUpdate::action(ActionId::new("act_123")), audit],
+        )
+        .await
+        .expect("Failed to update bindings");
+
+    assert_eq!(bindings.len(), 2);
+    assert_eq!(
+        bindings[1].action.as_ref().map(|a| a.name.as_str()),
+        Some("audit")
+    );
+}
+
+#[tokio::test]
+async fn test_test_action() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/actions/actions/act_123/test"))
+        .and(body_json(serde_json::json!({
+            "payload": { "user": { "user_id": "auth0|123" } }
+        })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "payload": { "command": [] }
+        })))
+        .mount(&server)
+        .await;
+
+    let result = client
+        .actions()
+        .test(
+            ActionId::new("act_123"),
+            serde_json::json!({ "user": { "user_id": "auth0|123" } }),
+        )
+        .await
+        .expect("Failed to test action");
+
+    assert!(result.payload["command"].is_array());
+}
+
+#[tokio::test]
+async fn test_wait_until_built() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/actions/actions/act_123"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "act_123",
+            "name": "add-roles",
+            "status": "building"
+        })))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/actions/actions/act_123"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "act_123",
+            "name": "add-roles",
+            "status": "built"
+        })))
+        .mount(&server)
+        .await;
+
+    let action = client
+        .actions()
+        .wait_until_built(ActionId::new("act_123"), &fast_poll())
+        .await
+        .expect("Failed to wait for build");
+
+    assert_eq!(action.status, Some(ActionBuildStatus::Built));
+}
+
+#[tokio::test]
+async fn test_wait_until_built_failure_and_timeout() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/actions/actions/act_failed"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "act_failed",
+            "name": "broken",
+            "status": "failed"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/actions/actions/act_stuck"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "act_stuck",
+            "name": "stuck",
+            "status": "pending"
+        })))
+        .mount(&server)
+        .await;
+
+    let failed = client
+        .actions()
+        .wait_until_built(ActionId::new("act_failed"), &fast_poll())
+        .await;
+    assert!(matches!(failed, Err(Auth0Error::OperationFailed(_))));
+
+    let stuck = client
+        .actions()
+        .wait_until_built(ActionId::new("act_stuck"), &fast_poll())
+        .await;
+    assert!(matches!(stuck, Err(Auth0Error::Timeout(_))));
+}
+
+#[tokio::test]
+async fn test_delete_action_force() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/actions/actions/act_123"))
+        .and(query_param("force", "true"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .actions()
+        .delete(ActionId::new("act_123"), true)
+        .await
+        .expect("Failed to delete action");
+}
@@ -0,0 +1,247 @@
+#![cfg(feature = "attack_protection")]
+
+use auth0_mgmt_api::{
+    AdminNotificationFrequency, BreachedPasswordDetection, BreachedPasswordMethod,
+    BreachedPasswordStageSettings, BreachedPasswordStages, BruteForceMode, BruteForceProtection,
+    ManagementClient, Shield, SuspiciousIpThrottling, ThrottlingStageSettings, ThrottlingStages,
+};
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_get_brute_force_protection() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/attack-protection/brute-force-protection"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "enabled": true,
+            "shields": ["block", "user_notification"],
+            "allowlist": ["10.0.0.0/8"],
+            "mode": "count_per_identifier_and_ip",
+            "max_attempts": 10
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = client
+        .attack_protection()
+        .get_brute_force_protection()
+        .await
+        .expect("Failed to get brute-force protection");
+
+    assert_eq!(settings.enabled, Some(true));
+    assert_eq!(
+        settings.shields,
+        Some(vec![Shield::Block, Shield::UserNotification])
+    );
+    assert_eq!(settings.allowlist, Some(vec!["10.0.0.0/8".to_string()]));
+    assert_eq!(settings.mode, Some(BruteForceMode::CountPerIdentifierAndIp));
+    assert_eq!(settings.max_attempts, Some(10));
+}
+
+#[tokio::test]
+async fn test_update_brute_force_protection() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/attack-protection/brute-force-protection"))
+        .and(body_json(serde_json::json!({
+            "mode": "count_per_identifier",
+            "max_attempts": 5
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "enabled": true,
+            "shields": ["block"],
+            "allowlist": [],
+            "mode": "count_per_identifier",
+            "max_attempts": 5
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = client
+        .attack_protection()
+        .update_brute_force_protection(BruteForceProtection {
+            mode: Some(BruteForceMode::CountPerIdentifier),
+            max_attempts: Some(5),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to update brute-force protection");
+
+    assert_eq!(settings.max_attempts, Some(5));
+}
+
+#[tokio::test]
+async fn test_get_breached_password_detection() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v2/attack-protection/breached-password-detection",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "enabled": true,
+            "shields": ["admin_notification"],
+            "admin_notification_frequency": ["daily", "fortnightly"],
+            "method": "standard",
+            "stage": {
+                "pre-user-registration": { "shields": ["block"] },
+                "pre-change-password": { "shields": [] }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = client
+        .attack_protection()
+        .get_breached_password_detection()
+        .await
+        .expect("Failed to get breached password detection");
+
+    assert_eq!(settings.shields, Some(vec![Shield::AdminNotification]));
+    assert_eq!(
+        settings.admin_notification_frequency,
+        Some(vec![
+            AdminNotificationFrequency::Daily,
+            AdminNotificationFrequency::Other("fortnightly".into())
+        ])
+    );
+    assert_eq!(settings.method, Some(BreachedPasswordMethod::Standard));
+    let stage = settings.stage.expect("stage should be present");
+    assert_eq!(
+        stage.pre_user_registration.and_then(|s| s.shields),
+        Some(vec![Shield::Block])
+    );
+    assert_eq!(
+        stage.pre_change_password.and_then(|s| s.shields),
+        Some(vec![])
+    );
+}
+
+#[tokio::test]
+async fn test_update_breached_password_detection() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/api/v2/attack-protection/breached-password-detection",
+        ))
+        .and(body_json(serde_json::json!({
+            "enabled": true,
+            "stage": {
+                "pre-change-password": { "shields": ["block", "admin_notification"] }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "enabled": true
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = client
+        .attack_protection()
+        .update_breached_password_detection(BreachedPasswordDetection {
+            enabled: Some(true),
+            stage: Some(BreachedPasswordStages {
+                pre_change_password: Some(BreachedPasswordStageSettings {
+                    shields: Some(vec![Shield::Block, Shield::AdminNotification]),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to update breached password detection");
+
+    assert_eq!(settings.enabled, Some(true));
+}
+
+#[tokio::test]
+async fn test_suspicious_ip_throttling() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/attack-protection/suspicious-ip-throttling"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "enabled": true,
+            "shields": ["block", "admin_notification"],
+            "allowlist": [],
+            "stage": {
+                "pre-login": { "max_attempts": 100, "rate": 864000 },
+                "pre-user-registration": { "max_attempts": 50, "rate": 1200 }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/attack-protection/suspicious-ip-throttling"))
+        .and(body_json(serde_json::json!({
+            "allowlist": ["203.0.113.7"],
+            "stage": { "pre-login": { "max_attempts": 20 } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "enabled": true,
+            "allowlist": ["203.0.113.7"],
+            "stage": { "pre-login": { "max_attempts": 20, "rate": 864000 } }
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = client
+        .attack_protection()
+        .get_suspicious_ip_throttling()
+        .await
+        .expect("Failed to get suspicious IP throttling");
+    let stage = settings.stage.expect("stage should be present");
+    assert_eq!(
+        stage.pre_login,
+        Some(ThrottlingStageSettings {
+            max_attempts: Some(100),
+            rate: Some(864000),
+        })
+    );
+
+    let updated = client
+        .attack_protection()
+        .update_suspicious_ip_throttling(SuspiciousIpThrottling {
+            allowlist: Some(vec!["203.0.113.7".into()]),
+            stage: Some(ThrottlingStages {
+                pre_login: Some(ThrottlingStageSettings {
+                    max_attempts: Some(20),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to update suspicious IP throttling");
+    assert_eq!(updated.allowlist, Some(vec!["203.0.113.7".to_string()]));
+}
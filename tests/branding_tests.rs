@@ -0,0 +1,346 @@
+#![cfg(feature = "branding")]
+
+use auth0_mgmt_api::{
+    BrandingColors, BrandingThemeRequest, ManagementClient, Patch, PromptCustomText,
+    PromptLanguage, PromptName, PromptSettings, UniversalLoginExperience, UpdateBrandingRequest,
+};
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+fn theme_json() -> serde_json::Value {
+    serde_json::json!({
+        "themeId": "theme_1",
+        "displayName": "Default",
+        "borders": { "button_border_radius": 3, "show_widget_shadow": true },
+        "colors": { "primary_button": "#635dff", "links_focused_components": "#635dff" },
+        "fonts": { "font_url": "", "links_style": "normal" },
+        "page_background": { "background_color": "#000000", "page_layout": "center" },
+        "widget": { "logo_position": "center", "logo_height": 52 }
+    })
+}
+
+#[tokio::test]
+async fn test_get_branding() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/branding"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "colors": {
+                "primary": "#0059d6",
+                "page_background": { "type": "linear-gradient", "start": "#fff", "end": "#000", "angle_deg": 35 }
+            },
+            "logo_url": "https://cdn.example.com/logo.png",
+            "font": { "url": "https://cdn.example.com/font.woff" }
+        })))
+        .mount(&server)
+        .await;
+
+    let branding = client
+        .branding()
+        .get()
+        .await
+        .expect("Failed to get branding");
+
+    let colors = branding.colors.expect("colors should be present");
+    assert_eq!(colors.primary.as_deref(), Some("#0059d6"));
+    assert_eq!(
+        colors.page_background.expect("page_background")["type"],
+        "linear-gradient"
+    );
+    assert_eq!(
+        branding.logo_url.as_deref(),
+        Some("https://cdn.example.com/logo.png")
+    );
+    assert!(branding.favicon_url.is_none());
+    assert_eq!(
+        branding.font.and_then(|f| f.url).as_deref(),
+        Some("https://cdn.example.com/font.woff")
+    );
+}
+
+#[tokio::test]
+async fn test_update_branding() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/branding"))
+        .and(body_json(serde_json::json!({
+            "colors": { "primary": "#ff5500" },
+            "favicon_url": null
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "colors": { "primary": "#ff5500" }
+        })))
+        .mount(&server)
+        .await;
+
+    let request = UpdateBrandingRequest {
+        colors: Patch::Value(BrandingColors {
+            primary: Some("#ff5500".into()),
+            ..Default::default()
+        }),
+        favicon_url: Patch::Null,
+        ..Default::default()
+    };
+    let branding = client
+        .branding()
+        .update(request)
+        .await
+        .expect("Failed to update branding");
+
+    assert_eq!(
+        branding.colors.and_then(|c| c.primary).as_deref(),
+        Some("#ff5500")
+    );
+}
+
+#[tokio::test]
+async fn test_theme_round_trip() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/branding/themes/default"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(theme_json()))
+        .mount(&server)
+        .await;
+
+    let mut expected = theme_json();
+    expected.as_object_mut().unwrap().remove("themeId");
+    expected["colors"]["primary_button"] = "#ff5500".into();
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/branding/themes/theme_1"))
+        .and(body_json(&expected))
+        .respond_with(ResponseTemplate::new(200).set_body_json(theme_json()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut theme = client
+        .branding()
+        .get_default_theme()
+        .await
+        .expect("Failed to get default theme");
+    assert_eq!(theme.theme_id, "theme_1");
+    assert_eq!(theme.display_name.as_deref(), Some("Default"));
+    assert_eq!(theme.settings.widget["logo_height"], 52);
+
+    theme
+        .settings
+        .colors
+        .insert("primary_button".into(), "#ff5500".into());
+    let id = theme.theme_id.clone();
+    client
+        .branding()
+        .update_theme(&id, BrandingThemeRequest::from(theme))
+        .await
+        .expect("Failed to update theme");
+}
+
+#[tokio::test]
+async fn test_create_and_delete_theme() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/branding/themes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(theme_json()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/branding/themes/theme_1"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let theme = client
+        .branding()
+        .create_theme(BrandingThemeRequest {
+            display_name: Some("Default".into()),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create theme");
+
+    client
+        .branding()
+        .delete_theme(&theme.theme_id)
+        .await
+        .expect("Failed to delete theme");
+}
+
+#[tokio::test]
+async fn test_universal_login_template() {
+    let (server, client) = setup_mock_server().await;
+    let html = "<html><head>{%- auth0:head -%}</head><body>{%- auth0:widget -%}</body></html>";
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/branding/templates/universal-login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "body": html
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/branding/templates/universal-login"))
+        .and(body_json(serde_json::json!({ "template": html })))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/branding/templates/universal-login"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let template = client
+        .branding()
+        .get_universal_login_template()
+        .await
+        .expect("Failed to get template");
+    assert_eq!(template.body, html);
+
+    client
+        .branding()
+        .set_universal_login_template(html)
+        .await
+        .expect("Failed to set template");
+    client
+        .branding()
+        .delete_universal_login_template()
+        .await
+        .expect("Failed to delete template");
+}
+
+#[tokio::test]
+async fn test_prompt_settings() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/prompts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "universal_login_experience": "new",
+            "identifier_first": false,
+            "webauthn_platform_first_factor": false
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/prompts"))
+        .and(body_json(serde_json::json!({ "identifier_first": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "universal_login_experience": "new",
+            "identifier_first": true,
+            "webauthn_platform_first_factor": false
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = client
+        .prompts()
+        .get_settings()
+        .await
+        .expect("Failed to get prompt settings");
+    assert_eq!(
+        settings.universal_login_experience,
+        Some(UniversalLoginExperience::New)
+    );
+
+    let updated = client
+        .prompts()
+        .update_settings(PromptSettings {
+            identifier_first: Some(true),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to update prompt settings");
+    assert_eq!(updated.identifier_first, Some(true));
+}
+
+#[tokio::test]
+async fn test_prompt_custom_text() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/prompts/login-id/custom-text/fr-CA"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login-id": { "title": "Bienvenue", "description": "Connectez-vous" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/prompts/mfa-sms/custom-text/x-klingon"))
+        .and(body_json(serde_json::json!({
+            "mfa-sms-challenge": { "title": "nuqneH" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let text = client
+        .prompts()
+        .get_custom_text(PromptName::LoginId, PromptLanguage::FrenchCanada)
+        .await
+        .expect("Failed to get custom text");
+    assert_eq!(text["login-id"]["title"], "Bienvenue");
+
+    let mut text = PromptCustomText::new();
+    text.entry("mfa-sms-challenge".into())
+        .or_default()
+        .insert("title".into(), "nuqneH".into());
+    client
+        .prompts()
+        .set_custom_text(
+            PromptName::MfaSms,
+            PromptLanguage::Other("x-klingon".into()),
+            &text,
+        )
+        .await
+        .expect("Failed to set custom text");
+}
+
+#[test]
+fn test_prompt_enums_round_trip() {
+    let name: PromptName = serde_json::from_value(serde_json::json!("mfa-recovery-code")).unwrap();
+    assert_eq!(name, PromptName::MfaRecoveryCode);
+    assert_eq!(name.as_str(), "mfa-recovery-code");
+
+    let language: PromptLanguage = serde_json::from_value(serde_json::json!("zh-TW")).unwrap();
+    assert_eq!(language, PromptLanguage::ChineseTraditional);
+
+    let unknown: PromptLanguage = serde_json::from_value(serde_json::json!("eu-ES")).unwrap();
+    assert_eq!(unknown.as_str(), "eu-ES");
+}
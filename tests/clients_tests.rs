@@ -1,5 +1,5 @@
 use auth0_mgmt_api::{
-    AppType, ClientId, CreateClientRequest, ListClientsParams, ManagementClient,
+    AppType, ClientId, CreateClientRequest, ListClientsParams, ManagementClient, Patch,
     UpdateClientRequest,
 };
 
@@ -300,9 +300,9 @@ async fn test_update_client() {
     });
 
     let request = UpdateClientRequest {
-        name: Some("Updated Application".to_string()),
-        description: Some("Updated description".to_string()),
-        callbacks: Some(vec!["https://updated.example.com/callback".to_string()]),
+        name: Patch::Value("Updated Application".to_string()),
+        description: Patch::Value("Updated description".to_string()),
+        callbacks: Patch::Value(vec!["https://updated.example.com/callback".to_string()]),
         ..Default::default()
     };
 
@@ -495,3 +495,72 @@ async fn test_get_client_with_special_characters_in_id() {
 
     assert_eq!(app.client_id, "client/with/slashes");
 }
+
+#[tokio::test]
+async fn test_create_client_with_builder() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/clients"))
+        .and(bearer_token("test_token"))
+        .and(body_json(serde_json::json!({
+            "name": "Built Application",
+            "app_type": "regular_web",
+            "callbacks": ["https://built.example.com/callback"]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "client_id": "built_client_id",
+            "name": "Built Application"
+        })))
+        .mount(&server)
+        .await;
+
+    let request = CreateClientRequest::builder()
+        .name("Built Application")
+        .app_type(AppType::RegularWeb)
+        .callbacks(["https://built.example.com/callback"])
+        .build()
+        .expect("Failed to build request");
+
+    let app = client
+        .clients()
+        .create(request)
+        .await
+        .expect("Failed to create client");
+
+    assert_eq!(app.client_id, "built_client_id");
+}
+
+#[test]
+fn test_create_client_builder_validation() {
+    let empty_name = CreateClientRequest::builder().name("  ").build();
+    assert!(matches!(
+        empty_name,
+        Err(auth0_mgmt_api::Auth0Error::Configuration(_))
+    ));
+
+    let bad_callback = CreateClientRequest::builder()
+        .name("App")
+        .callbacks(["not a url"])
+        .build();
+    assert!(matches!(
+        bad_callback,
+        Err(auth0_mgmt_api::Auth0Error::Configuration(_))
+    ));
+}
+
+#[test]
+fn test_update_client_builder() {
+    let request = UpdateClientRequest::builder()
+        .name("Renamed")
+        .clear_description()
+        .build()
+        .expect("Failed to build request");
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({ "name": "Renamed", "description": null })
+    );
+
+    assert!(UpdateClientRequest::builder().build().is_err());
+}
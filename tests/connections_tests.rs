@@ -1,7 +1,10 @@
 use auth0_mgmt_api::{
-    ConnectionId, ConnectionStrategy, CreateConnectionRequest, ListConnectionsParams,
-    ManagementClient, UpdateConnectionRequest,
+    CheckpointParams, ClientId, ConnectionId, ConnectionOptions, ConnectionStrategy,
+    CreateConnectionRequest, CreateScimTokenRequest, DatabaseConnectionOptions,
+    EnabledClientUpdate, ListConnectionsParams, ManagementClient, PasswordPolicy, Patch,
+    ScimConfigurationRequest, SocialConnectionOptions, UpdateConnectionRequest,
 };
+use secrecy::ExposeSecret;
 use wiremock::matchers::{bearer_token, body_json, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -399,8 +402,8 @@ async fn test_update_connection() {
     });
 
     let request = UpdateConnectionRequest {
-        display_name: Some("Updated Display Name".to_string()),
-        enabled_clients: Some(vec!["client_123".to_string(), "client_789".to_string()]),
+        display_name: Patch::Value("Updated Display Name".to_string()),
+        enabled_clients: Patch::Value(vec!["client_123".to_string(), "client_789".to_string()]),
         ..Default::default()
     };
 
@@ -452,7 +455,7 @@ async fn test_update_connection_options() {
     });
 
     let request = UpdateConnectionRequest {
-        options: Some(options.clone()),
+        options: Patch::Value(options.clone()),
         ..Default::default()
     };
 
@@ -600,3 +603,601 @@ async fn test_get_connection_with_special_characters_in_id() {
 
     assert_eq!(connection.id, "con/with/slashes");
 }
+
+#[test]
+fn test_create_connection_builder() {
+    let request = CreateConnectionRequest::builder()
+        .strategy(ConnectionStrategy::GoogleOAuth2)
+        .name("google-oauth2")
+        .enabled_clients(["client_123"])
+        .build()
+        .expect("Failed to build request");
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({
+            "name": "google-oauth2",
+            "strategy": "google-oauth2",
+            "enabled_clients": ["client_123"]
+        })
+    );
+}
+
+#[test]
+fn test_create_connection_builder_rejects_invalid_name() {
+    let result = CreateConnectionRequest::builder()
+        .name("My Database")
+        .strategy(ConnectionStrategy::Auth0Database)
+        .build();
+
+    assert!(matches!(
+        result,
+        Err(auth0_mgmt_api::Auth0Error::Configuration(_))
+    ));
+}
+
+#[test]
+fn test_update_connection_builder() {
+    let request = UpdateConnectionRequest::builder()
+        .display_name("Employees")
+        .clear_metadata()
+        .build()
+        .expect("Failed to build request");
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({ "display_name": "Employees", "metadata": null })
+    );
+
+    assert!(UpdateConnectionRequest::builder().build().is_err());
+}
+
+#[tokio::test]
+async fn test_connection_typed_database_options() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_db"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "Username-Password-Authentication",
+            "strategy": "auth0",
+            "options": {
+                "passwordPolicy": "good",
+                "password_complexity_options": { "min_length": 12 },
+                "password_history": { "enable": true, "size": 5 },
+                "mfa": { "active": true, "return_enroll_settings": true },
+                "brute_force_protection": true,
+                "some_future_flag": "kept"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let connection = client
+        .connections()
+        .get(ConnectionId::new("con_db"))
+        .await
+        .expect("Failed to get connection");
+
+    match connection.typed_options() {
+        Some(ConnectionOptions::Database(db)) => {
+            assert_eq!(db.password_policy, Some(PasswordPolicy::Good));
+            assert_eq!(db.password_complexity_options.unwrap().min_length, Some(12));
+            assert_eq!(db.password_history.unwrap().size, Some(5));
+            assert_eq!(db.mfa.unwrap().active, Some(true));
+            assert_eq!(
+                db.extra.get("some_future_flag"),
+                Some(&serde_json::json!("kept"))
+            );
+        }
+        other => panic!("Expected database options, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_connection_typed_saml_and_fallback_options() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_saml"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_saml",
+            "name": "corp-saml",
+            "strategy": "samlp",
+            "options": {
+                "signInEndpoint": "https://idp.example.com/sso",
+                "signingCert": "MIIC...",
+                "signSAMLRequest": true,
+                "signatureAlgorithm": "rsa-sha256"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_adfs"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_adfs",
+            "name": "corp-adfs",
+            "strategy": "adfs",
+            "options": { "adfs_server": "https://adfs.example.com" }
+        })))
+        .mount(&server)
+        .await;
+
+    let saml = client
+        .connections()
+        .get(ConnectionId::new("con_saml"))
+        .await
+        .expect("Failed to get connection");
+    match saml.typed_options() {
+        Some(ConnectionOptions::Saml(options)) => {
+            assert_eq!(options.signing_cert.as_deref(), Some("MIIC..."));
+            assert_eq!(options.sign_saml_request, Some(true));
+        }
+        other => panic!("Expected SAML options, got {:?}", other),
+    }
+
+    let adfs = client
+        .connections()
+        .get(ConnectionId::new("con_adfs"))
+        .await
+        .expect("Failed to get connection");
+    assert!(matches!(
+        adfs.typed_options(),
+        Some(ConnectionOptions::Other(_))
+    ));
+}
+
+#[test]
+fn test_create_connection_with_social_client_secret() {
+    let options = SocialConnectionOptions {
+        client_id: Some("google-client".to_string()),
+        client_secret: Some("google-secret".into()),
+        ..Default::default()
+    };
+    assert!(!format!("{:?}", options).contains("google-secret"));
+
+    let request = CreateConnectionRequest::builder()
+        .name("google")
+        .strategy(ConnectionStrategy::GoogleOAuth2)
+        .options(options)
+        .build()
+        .expect("Failed to build request");
+
+    assert_eq!(
+        request.options,
+        Some(serde_json::json!({
+            "client_id": "google-client",
+            "client_secret": "google-secret"
+        }))
+    );
+}
+
+#[test]
+fn test_create_connection_with_typed_options() {
+    let options = DatabaseConnectionOptions {
+        password_policy: Some(PasswordPolicy::Excellent),
+        requires_username: Some(true),
+        ..Default::default()
+    };
+
+    let request = CreateConnectionRequest::builder()
+        .name("typed-db")
+        .strategy(ConnectionStrategy::Auth0Database)
+        .options(options)
+        .build()
+        .expect("Failed to build request");
+
+    assert_eq!(
+        request.options,
+        Some(serde_json::json!({
+            "passwordPolicy": "excellent",
+            "requires_username": true
+        }))
+    );
+}
+
+#[tokio::test]
+async fn test_connection_status() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_ad/status"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_offline/status"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "statusCode": 404,
+            "error": "Not Found",
+            "message": "The connection is offline"
+        })))
+        .mount(&server)
+        .await;
+
+    client
+        .connections()
+        .status(ConnectionId::new("con_ad"))
+        .await
+        .expect("Connection should be online");
+
+    let offline = client
+        .connections()
+        .status(ConnectionId::new("con_offline"))
+        .await;
+    assert!(matches!(
+        offline,
+        Err(auth0_mgmt_api::Auth0Error::Api { status: 404, .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_connection_user_by_email() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/connections/con_db/users"))
+        .and(query_param("email", "user+tag@example.com"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .connections()
+        .delete_user_by_email(ConnectionId::new("con_db"), "user+tag@example.com")
+        .await
+        .expect("Failed to delete user by email");
+}
+
+#[tokio::test]
+async fn test_enabled_clients() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_db/clients"))
+        .and(query_param("take", "2"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "clients": [{ "client_id": "client_1" }, { "client_id": "client_2" }],
+            "next": "checkpoint_2"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/connections/con_db/clients"))
+        .and(bearer_token("test_token"))
+        .and(body_json(serde_json::json!([
+            { "client_id": "client_3", "status": true },
+            { "client_id": "client_1", "status": false }
+        ])))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let page = client
+        .connections()
+        .list_enabled_clients(
+            ConnectionId::new("con_db"),
+            Some(CheckpointParams {
+                take: Some(2),
+                ..Default::default()
+            }),
+        )
+        .await
+        .expect("Failed to list enabled clients");
+
+    assert_eq!(page.clients.len(), 2);
+    assert_eq!(page.clients[0].client_id, ClientId::new("client_1"));
+    assert_eq!(page.next.as_deref(), Some("checkpoint_2"));
+
+    client
+        .connections()
+        .update_enabled_clients(
+            ConnectionId::new("con_db"),
+            vec![
+                EnabledClientUpdate::enable(ClientId::new("client_3")),
+                EnabledClientUpdate::disable(ClientId::new("client_1")),
+            ],
+        )
+        .await
+        .expect("Failed to update enabled clients");
+}
+
+#[tokio::test]
+async fn test_scim_configuration_and_tokens() {
+    let (server, client) = setup_mock_server().await;
+
+    let config = serde_json::json!({
+        "connection_id": "con_okta",
+        "connection_name": "okta",
+        "strategy": "okta",
+        "tenant_name": "example",
+        "user_id_attribute": "externalId",
+        "mapping": [{ "auth0": "email", "scim": "emails[primary eq true].value" }],
+        "created_at": "2024-01-01T00:00:00.000Z",
+        "updated_on": "2024-01-01T00:00:00.000Z"
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/connections/con_okta/scim-configuration"))
+        .and(bearer_token("test_token"))
+        .and(body_json(
+            serde_json::json!({ "user_id_attribute": "externalId" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&config))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/connections/con_okta/scim-configuration/tokens",
+        ))
+        .and(bearer_token("test_token"))
+        .and(body_json(serde_json::json!({
+            "scopes": ["get:users", "post:users"],
+            "token_lifetime": 86400
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "token_id": "tok_1",
+            "token": "secret-token-value",
+            "scopes": ["get:users", "post:users"]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/connections/con_okta/scim-configuration/tokens/tok_1",
+        ))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let created = client
+        .connections()
+        .create_scim_configuration(
+            ConnectionId::new("con_okta"),
+            ScimConfigurationRequest {
+                user_id_attribute: Some("externalId".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("Failed to create SCIM configuration");
+    assert_eq!(created.connection_id, "con_okta");
+    assert_eq!(created.strategy, Some(ConnectionStrategy::Okta));
+    assert_eq!(created.mapping.unwrap()[0].auth0, "email");
+
+    let token = client
+        .connections()
+        .create_scim_token(
+            ConnectionId::new("con_okta"),
+            CreateScimTokenRequest {
+                scopes: Some(vec!["get:users".to_string(), "post:users".to_string()]),
+                token_lifetime: Some(86400),
+            },
+        )
+        .await
+        .expect("Failed to create SCIM token");
+    assert_eq!(token.token.expose_secret(), "secret-token-value");
+
+    client
+        .connections()
+        .delete_scim_token(ConnectionId::new("con_okta"), &token.token_id)
+        .await
+        .expect("Failed to delete SCIM token");
+}
+
+#[tokio::test]
+async fn test_enable_for_client_uses_per_client_endpoint() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/connections/con_db/clients"))
+        .and(bearer_token("test_token"))
+        .and(body_json(serde_json::json!([
+            { "client_id": "client_new", "status": true }
+        ])))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_db"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    client
+        .connections()
+        .enable_for_client(ConnectionId::new("con_db"), ClientId::new("client_new"))
+        .await
+        .expect("Failed to enable connection for client");
+}
+
+#[tokio::test]
+async fn test_disable_for_client_falls_back_to_read_modify_write() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/connections/con_db/clients"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "statusCode": 404,
+            "error": "Not Found",
+            "message": "Not Found"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_db"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "db",
+            "strategy": "auth0",
+            "enabled_clients": ["client_1", "client_2"]
+        })))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/connections/con_db"))
+        .and(body_json(
+            serde_json::json!({ "enabled_clients": ["client_1"] }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "db",
+            "strategy": "auth0",
+            "enabled_clients": ["client_1"]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_db"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "db",
+            "strategy": "auth0",
+            "enabled_clients": ["client_1"]
+        })))
+        .mount(&server)
+        .await;
+
+    client
+        .connections()
+        .disable_for_client(ConnectionId::new("con_db"), ClientId::new("client_2"))
+        .await
+        .expect("Failed to disable connection for client");
+}
+
+#[tokio::test]
+async fn test_enable_for_client_detects_concurrent_modification() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/connections/con_db/clients"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    // Another writer keeps overwriting the list, so the change never sticks.
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_db"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "db",
+            "strategy": "auth0",
+            "enabled_clients": ["client_1"]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/connections/con_db"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "db",
+            "strategy": "auth0"
+        })))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let result = client
+        .connections()
+        .enable_for_client(ConnectionId::new("con_db"), ClientId::new("client_new"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(auth0_mgmt_api::Auth0Error::ConcurrentModification(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_enable_for_client_rereads_list_changed_by_another_writer() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/connections/con_db/clients"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    // First read, then another writer enables client_2 before the pre-write check.
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_db"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "db",
+            "strategy": "auth0",
+            "enabled_clients": ["client_1"]
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_db"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "db",
+            "strategy": "auth0",
+            "enabled_clients": ["client_1", "client_2"]
+        })))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/connections/con_db"))
+        .and(body_json(serde_json::json!({
+            "enabled_clients": ["client_1", "client_2", "client_new"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "db",
+            "strategy": "auth0"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/connections/con_db"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "con_db",
+            "name": "db",
+            "strategy": "auth0",
+            "enabled_clients": ["client_2", "client_1", "client_new"]
+        })))
+        .mount(&server)
+        .await;
+
+    client
+        .connections()
+        .enable_for_client(ConnectionId::new("con_db"), ClientId::new("client_new"))
+        .await
+        .expect("Failed to enable connection for client");
+}
@@ -0,0 +1,260 @@
+#![cfg(feature = "custom_domains")]
+
+use std::time::Duration;
+
+use auth0_mgmt_api::{
+    Auth0Error, CreateCustomDomainRequest, CustomClientIpHeader, CustomDomainStatus,
+    CustomDomainType, ManagementClient, Patch, PollConfig, TlsPolicy, UpdateCustomDomainRequest,
+    VerificationMethodType,
+};
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+fn fast_poll() -> PollConfig {
+    PollConfig {
+        initial_interval: Duration::from_millis(5),
+        max_interval: Duration::from_millis(10),
+        multiplier: 2.0,
+        timeout: Duration::from_millis(200),
+    }
+}
+
+fn domain_json(status: &str) -> serde_json::Value {
+    serde_json::json!({
+        "custom_domain_id": "cd_123",
+        "domain": "login.example.com",
+        "primary": true,
+        "status": status,
+        "type": "auth0_managed_certs",
+        "verification": {
+            "methods": [
+                { "name": "cname", "record": "example-cd-abc.edge.tenants.auth0.com" }
+            ]
+        },
+        "tls_policy": "recommended"
+    })
+}
+
+#[tokio::test]
+async fn test_list_custom_domains() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/custom-domains"))
+        .and(bearer_token("test_token"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([domain_json("ready")])),
+        )
+        .mount(&server)
+        .await;
+
+    let domains = client
+        .custom_domains()
+        .list()
+        .await
+        .expect("Failed to list custom domains");
+
+    assert_eq!(domains.len(), 1);
+    assert!(domains[0].is_ready());
+    assert!(domains[0].primary);
+    assert_eq!(domains[0].domain_type, CustomDomainType::Auth0ManagedCerts);
+    assert_eq!(domains[0].tls_policy, Some(TlsPolicy::Recommended));
+}
+
+#[tokio::test]
+async fn test_create_custom_domain() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/custom-domains"))
+        .and(body_json(serde_json::json!({
+            "domain": "login.example.com",
+            "type": "self_managed_certs",
+            "custom_client_ip_header": "cf-connecting-ip"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "custom_domain_id": "cd_456",
+            "domain": "login.example.com",
+            "primary": false,
+            "status": "pending_verification",
+            "type": "self_managed_certs",
+            "origin_domain_name": "example-cd-xyz.edge.tenants.auth0.com",
+            "verification": {
+                "methods": [
+                    {
+                        "name": "txt",
+                        "record": "auth0-domain-verification=abc",
+                        "domain": "_cf-custom-hostname.login.example.com"
+                    }
+                ]
+            },
+            "custom_client_ip_header": "cf-connecting-ip"
+        })))
+        .mount(&server)
+        .await;
+
+    let request = CreateCustomDomainRequest {
+        custom_client_ip_header: Some(CustomClientIpHeader::CfConnectingIp),
+        ..CreateCustomDomainRequest::new("login.example.com", CustomDomainType::SelfManagedCerts)
+    };
+    let domain = client
+        .custom_domains()
+        .create(request)
+        .await
+        .expect("Failed to create custom domain");
+
+    assert_eq!(domain.status, CustomDomainStatus::PendingVerification);
+    assert!(!domain.is_ready());
+    let methods = domain.verification_methods();
+    assert_eq!(methods.len(), 1);
+    assert_eq!(methods[0].name, VerificationMethodType::Txt);
+    assert_eq!(
+        methods[0].domain.as_deref(),
+        Some("_cf-custom-hostname.login.example.com")
+    );
+    assert_eq!(
+        domain.origin_domain_name.as_deref(),
+        Some("example-cd-xyz.edge.tenants.auth0.com")
+    );
+}
+
+#[tokio::test]
+async fn test_get_update_and_delete_custom_domain() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/custom-domains/cd_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(domain_json("ready")))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/custom-domains/cd_123"))
+        .and(body_json(serde_json::json!({
+            "tls_policy": "recommended",
+            "custom_client_ip_header": null
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(domain_json("ready")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/custom-domains/cd_123"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let domain = client
+        .custom_domains()
+        .get("cd_123")
+        .await
+        .expect("Failed to get custom domain");
+    assert_eq!(domain.domain, "login.example.com");
+
+    client
+        .custom_domains()
+        .update(
+            "cd_123",
+            UpdateCustomDomainRequest {
+                tls_policy: Some(TlsPolicy::Recommended),
+                custom_client_ip_header: Patch::Null,
+            },
+        )
+        .await
+        .expect("Failed to update custom domain");
+
+    client
+        .custom_domains()
+        .delete("cd_123")
+        .await
+        .expect("Failed to delete custom domain");
+}
+
+#[tokio::test]
+async fn test_wait_until_verified() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/custom-domains/cd_123/verify"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(domain_json("pending_verification")))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/custom-domains/cd_123/verify"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(domain_json("ready")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let domain = client
+        .custom_domains()
+        .wait_until_verified("cd_123", &fast_poll())
+        .await
+        .expect("Failed to wait for verification");
+
+    assert!(domain.is_ready());
+}
+
+#[tokio::test]
+async fn test_wait_until_verified_times_out() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/custom-domains/cd_123/verify"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(domain_json("pending_verification")))
+        .mount(&server)
+        .await;
+
+    let result = client
+        .custom_domains()
+        .wait_until_verified("cd_123", &fast_poll())
+        .await;
+
+    assert!(matches!(result, Err(Auth0Error::Timeout(_))));
+}
+
+#[tokio::test]
+async fn test_wait_until_verified_fails_when_disabled() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/custom-domains/cd_123/verify"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(domain_json("disabled")))
+        .mount(&server)
+        .await;
+
+    let result = client
+        .custom_domains()
+        .wait_until_verified("cd_123", &fast_poll())
+        .await;
+
+    assert!(matches!(result, Err(Auth0Error::OperationFailed(_))));
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "device_credentials")]
+
+use auth0_mgmt_api::{
+    ClientId, CreatePublicKeyRequest, DeviceCredentialType, ListDeviceCredentialsParams,
+    ManagementClient, UserId,
+};
+use wiremock::matchers::{bearer_token, body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+fn credential_json(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "device_name": "Pixel 8",
+        "device_id": "device_abc",
+        "type": "refresh_token",
+        "user_id": "auth0|123",
+        "client_id": "client_123"
+    })
+}
+
+#[tokio::test]
+async fn test_list_device_credentials_by_user_and_type() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/device-credentials"))
+        .and(bearer_token("test_token"))
+        .and(query_param("user_id", "auth0|123"))
+        .and(query_param("type", "refresh_token"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([credential_json("dcr_1")])),
+        )
+        .mount(&server)
+        .await;
+
+    let params = ListDeviceCredentialsParams {
+        user_id: Some(UserId::new("auth0|123")),
+        credential_type: Some(DeviceCredentialType::RefreshToken),
+        ..Default::default()
+    };
+    let credentials = client
+        .device_credentials()
+        .list(Some(params))
+        .await
+        .unwrap();
+
+    assert_eq!(credentials.len(), 1);
+    assert_eq!(credentials[0].id, "dcr_1");
+    assert_eq!(
+        credentials[0].credential_type,
+        Some(DeviceCredentialType::RefreshToken)
+    );
+    assert_eq!(
+        credentials[0].client_id.as_ref().map(|c| c.as_str()),
+        Some("client_123")
+    );
+}
+
+#[tokio::test]
+async fn test_list_all_device_credentials_pages_through_results() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/device-credentials"))
+        .and(query_param("client_id", "client_123"))
+        .and(query_param("include_totals", "true"))
+        .and(query_param("per_page", "2"))
+        .and(query_param("page", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "device_credentials": [credential_json("dcr_1"), credential_json("dcr_2")],
+            "start": 0,
+            "limit": 2,
+            "total": 3
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/device-credentials"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "device_credentials": [credential_json("dcr_3")],
+            "start": 2,
+            "limit": 2,
+            "total": 3
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let params = ListDeviceCredentialsParams {
+        client_id: Some(ClientId::new("client_123")),
+        per_page: Some(2),
+        ..Default::default()
+    };
+    let credentials = client
+        .device_credentials()
+        .list_all(Some(params))
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = credentials.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, ["dcr_1", "dcr_2", "dcr_3"]);
+}
+
+#[tokio::test]
+async fn test_create_public_key() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/device-credentials"))
+        .and(body_json(serde_json::json!({
+            "device_name": "Pixel 8",
+            "value": "cHVibGljLWtleQ==",
+            "device_id": "device_abc",
+            "client_id": "client_123",
+            "type": "public_key"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "dcr_new"
+        })))
+        .mount(&server)
+        .await;
+
+    let request = CreatePublicKeyRequest {
+        client_id: Some(ClientId::new("client_123")),
+        ..CreatePublicKeyRequest::new("Pixel 8", "cHVibGljLWtleQ==", "device_abc")
+    };
+    let created = client
+        .device_credentials()
+        .create_public_key(request)
+        .await
+        .unwrap();
+
+    assert_eq!(created.id, "dcr_new");
+}
+
+#[tokio::test]
+async fn test_delete_device_credential() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/device-credentials/dcr_1"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.device_credentials().delete("dcr_1").await.unwrap();
+}
@@ -0,0 +1,344 @@
+#![cfg(feature = "emails")]
+
+use auth0_mgmt_api::{
+    ApiKeyCredentials, CreateEmailProviderRequest, CreateEmailTemplateRequest,
+    EmailProviderCredentials, EmailTemplateName, ManagementClient, Patch, SmtpCredentials,
+    UpdateEmailProviderRequest, UpdateEmailTemplateRequest,
+};
+use wiremock::matchers::{bearer_token, body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_get_email_provider() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/emails/provider"))
+        .and(query_param(
+            "fields",
+            "name,enabled,credentials,settings,default_from_address",
+        ))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "smtp",
+            "enabled": true,
+            "default_from_address": "no-reply@example.com",
+            "credentials": {
+                "smtp_host": "smtp.example.com",
+                "smtp_port": 587,
+                "smtp_user": "mailer"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = client
+        .email_provider()
+        .get()
+        .await
+        .expect("Failed to get email provider");
+
+    assert_eq!(provider.enabled, Some(true));
+    assert_eq!(
+        provider.default_from_address.as_deref(),
+        Some("no-reply@example.com")
+    );
+    match provider.credentials {
+        EmailProviderCredentials::Smtp(smtp) => {
+            assert_eq!(smtp.smtp_host.as_deref(), Some("smtp.example.com"));
+            assert_eq!(smtp.smtp_port, Some(587));
+            assert!(smtp.smtp_pass.is_none());
+        }
+        other => panic!("Expected SMTP credentials, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_get_unknown_email_provider() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/emails/provider"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "custom",
+            "enabled": true
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = client
+        .email_provider()
+        .get()
+        .await
+        .expect("Failed to get email provider");
+
+    match provider.credentials {
+        EmailProviderCredentials::Other {
+            provider,
+            credentials,
+        } => {
+            assert_eq!(provider, "custom");
+            assert!(credentials.is_null());
+        }
+        other => panic!("Expected unknown provider, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_create_email_provider() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/emails/provider"))
+        .and(body_json(serde_json::json!({
+            "name": "sendgrid",
+            "credentials": { "api_key": "SG.secret" },
+            "enabled": true,
+            "default_from_address": "no-reply@example.com"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "name": "sendgrid",
+            "enabled": true,
+            "default_from_address": "no-reply@example.com",
+            "credentials": {}
+        })))
+        .mount(&server)
+        .await;
+
+    let request = CreateEmailProviderRequest {
+        enabled: Some(true),
+        default_from_address: Some("no-reply@example.com".into()),
+        ..CreateEmailProviderRequest::new(EmailProviderCredentials::Sendgrid(ApiKeyCredentials {
+            api_key: Some("SG.secret".into()),
+        }))
+    };
+    let provider = client
+        .email_provider()
+        .create(request)
+        .await
+        .expect("Failed to create email provider");
+
+    assert!(matches!(
+        provider.credentials,
+        EmailProviderCredentials::Sendgrid(ApiKeyCredentials { api_key: None })
+    ));
+}
+
+#[tokio::test]
+async fn test_update_email_provider() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/emails/provider"))
+        .and(body_json(serde_json::json!({
+            "enabled": false,
+            "default_from_address": null
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "sendgrid",
+            "enabled": false,
+            "credentials": {}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/emails/provider"))
+        .and(body_json(serde_json::json!({
+            "name": "smtp",
+            "credentials": {
+                "smtp_host": "smtp.example.com",
+                "smtp_port": 465,
+                "smtp_user": "mailer",
+                "smtp_pass": "hunter2"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "smtp",
+            "enabled": false,
+            "credentials": { "smtp_host": "smtp.example.com", "smtp_port": 465 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let disabled = client
+        .email_provider()
+        .update(UpdateEmailProviderRequest {
+            enabled: Patch::Value(false),
+            default_from_address: Patch::Null,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to disable email provider");
+    assert_eq!(disabled.enabled, Some(false));
+
+    let switched = client
+        .email_provider()
+        .update(UpdateEmailProviderRequest {
+            credentials: Some(EmailProviderCredentials::Smtp(SmtpCredentials {
+                smtp_host: Some("smtp.example.com".into()),
+                smtp_port: Some(465),
+                smtp_user: Some("mailer".into()),
+                smtp_pass: Some("hunter2".into()),
+            })),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to switch email provider");
+    assert!(matches!(
+        switched.credentials,
+        EmailProviderCredentials::Smtp(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_email_provider() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/emails/provider"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .email_provider()
+        .delete()
+        .await
+        .expect("Failed to delete email provider");
+}
+
+#[tokio::test]
+async fn test_get_email_template() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/email-templates/verify_email"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "template": "verify_email",
+            "body": "<html>{{ url }}</html>",
+            "from": "Example <no-reply@example.com>",
+            "subject": "Verify your email",
+            "syntax": "liquid",
+            "resultUrl": "https://example.com/verified",
+            "urlLifetimeInSeconds": 432000,
+            "includeEmailInRedirect": false,
+            "enabled": true
+        })))
+        .mount(&server)
+        .await;
+
+    let template = client
+        .email_templates()
+        .get(EmailTemplateName::VerifyEmail)
+        .await
+        .expect("Failed to get email template");
+
+    assert_eq!(template.template, EmailTemplateName::VerifyEmail);
+    assert_eq!(
+        template.result_url.as_deref(),
+        Some("https://example.com/verified")
+    );
+    assert_eq!(template.url_lifetime_in_seconds, Some(432000));
+    assert_eq!(template.include_email_in_redirect, Some(false));
+}
+
+#[tokio::test]
+async fn test_create_email_template() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/email-templates"))
+        .and(body_json(serde_json::json!({
+            "template": "blocked_account",
+            "subject": "Your account was blocked",
+            "body": "<html>{{ url }}</html>",
+            "from": "no-reply@example.com",
+            "syntax": "liquid",
+            "enabled": true,
+            "urlLifetimeInSeconds": 3600
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "template": "blocked_account",
+            "subject": "Your account was blocked",
+            "enabled": true
+        })))
+        .mount(&server)
+        .await;
+
+    let request = CreateEmailTemplateRequest {
+        url_lifetime_in_seconds: Some(3600),
+        ..CreateEmailTemplateRequest::new(
+            EmailTemplateName::BlockedAccount,
+            "Your account was blocked",
+            "<html>{{ url }}</html>",
+            "no-reply@example.com",
+        )
+    };
+    let template = client
+        .email_templates()
+        .create(request)
+        .await
+        .expect("Failed to create email template");
+
+    assert_eq!(template.template, EmailTemplateName::BlockedAccount);
+}
+
+#[tokio::test]
+async fn test_update_email_template() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/email-templates/reset_email"))
+        .and(body_json(serde_json::json!({
+            "enabled": false,
+            "resultUrl": null
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "template": "reset_email",
+            "enabled": false
+        })))
+        .mount(&server)
+        .await;
+
+    let request = UpdateEmailTemplateRequest {
+        enabled: Patch::Value(false),
+        result_url: Patch::Null,
+        ..Default::default()
+    };
+    let template = client
+        .email_templates()
+        .update(EmailTemplateName::ResetEmail, request)
+        .await
+        .expect("Failed to update email template");
+
+    assert_eq!(template.enabled, Some(false));
+    assert!(template.result_url.is_none());
+}
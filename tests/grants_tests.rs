@@ -0,0 +1,142 @@
+#![cfg(feature = "grants")]
+
+use auth0_mgmt_api::{ListGrantsParams, ManagementClient, UserId};
+use wiremock::matchers::{bearer_token, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+fn grant_json(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "clientID": "client_123",
+        "user_id": "auth0|123",
+        "audience": "https://api.example.com",
+        "scope": ["read:orders", "write:orders"]
+    })
+}
+
+#[tokio::test]
+async fn test_list_grants_by_audience() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/grants"))
+        .and(bearer_token("test_token"))
+        .and(query_param("audience", "https://api.example.com"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([grant_json("grant_1")])),
+        )
+        .mount(&server)
+        .await;
+
+    let params = ListGrantsParams {
+        audience: Some("https://api.example.com".to_string()),
+        ..Default::default()
+    };
+    let grants = client.grants().list(Some(params)).await.unwrap();
+
+    assert_eq!(grants.len(), 1);
+    assert_eq!(
+        grants[0].client_id.as_ref().map(|c| c.as_str()),
+        Some("client_123")
+    );
+    assert_eq!(grants[0].scope, ["read:orders", "write:orders"]);
+}
+
+#[tokio::test]
+async fn test_list_all_grants_pages_through_results() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/grants"))
+        .and(query_param("user_id", "auth0|123"))
+        .and(query_param("include_totals", "true"))
+        .and(query_param("page", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "grants": [grant_json("grant_1")],
+            "start": 0,
+            "limit": 1,
+            "total": 2
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/grants"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "grants": [grant_json("grant_2")],
+            "start": 1,
+            "limit": 1,
+            "total": 2
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let params = ListGrantsParams {
+        user_id: Some(UserId::new("auth0|123")),
+        per_page: Some(1),
+        ..Default::default()
+    };
+    let grants = client.grants().list_all(Some(params)).await.unwrap();
+
+    let ids: Vec<_> = grants.iter().map(|g| g.id.as_str()).collect();
+    assert_eq!(ids, ["grant_1", "grant_2"]);
+}
+
+#[tokio::test]
+async fn test_delete_grant() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/grants/grant_1"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.grants().delete("grant_1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_grants_by_user() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/grants"))
+        .and(query_param("user_id", "auth0|123"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .grants()
+        .delete_by_user(UserId::new("auth0|123"))
+        .await
+        .unwrap();
+}
@@ -0,0 +1,364 @@
+#![cfg(feature = "guardian")]
+
+use auth0_mgmt_api::{
+    ApnsConfig, CreateEnrollmentTicketRequest, EnrollmentStatus, GuardianFactorName,
+    ManagementClient, MfaPolicy, PhoneDeliveryMethod, PhoneProvider, PushProvider, SnsConfig,
+    TwilioConfig, UserId,
+};
+use secrecy::ExposeSecret;
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_list_factors() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/guardian/factors"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            { "name": "sms", "enabled": true, "trial_expired": false },
+            { "name": "push-notification", "enabled": false },
+            { "name": "brand-new-factor", "enabled": false }
+        ])))
+        .mount(&server)
+        .await;
+
+    let factors = client
+        .guardian()
+        .list_factors()
+        .await
+        .expect("Failed to list factors");
+
+    assert_eq!(factors.len(), 3);
+    assert_eq!(factors[0].name, GuardianFactorName::Sms);
+    assert!(factors[0].enabled);
+    assert_eq!(factors[1].name, GuardianFactorName::PushNotification);
+    assert_eq!(
+        factors[2].name,
+        GuardianFactorName::Other("brand-new-factor".into())
+    );
+}
+
+#[tokio::test]
+async fn test_set_factor_enabled() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/guardian/factors/webauthn-roaming"))
+        .and(body_json(serde_json::json!({ "enabled": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "enabled": true
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .guardian()
+        .set_factor_enabled(GuardianFactorName::WebauthnRoaming, true)
+        .await
+        .expect("Failed to enable factor");
+}
+
+#[tokio::test]
+async fn test_policies() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/guardian/policies"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/guardian/policies"))
+        .and(body_json(serde_json::json!(["all-applications"])))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!(["all-applications"])),
+        )
+        .mount(&server)
+        .await;
+
+    let policies = client
+        .guardian()
+        .get_policies()
+        .await
+        .expect("Failed to get policies");
+    assert!(policies.is_empty());
+
+    let policies = client
+        .guardian()
+        .set_policies(vec![MfaPolicy::AllApplications])
+        .await
+        .expect("Failed to set policies");
+    assert_eq!(policies, vec![MfaPolicy::AllApplications]);
+}
+
+#[tokio::test]
+async fn test_phone_provider_and_message_types() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/guardian/factors/phone/selected-provider"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "provider": "auth0"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/guardian/factors/phone/selected-provider"))
+        .and(body_json(
+            serde_json::json!({ "provider": "phone-message-hook" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "provider": "phone-message-hook"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/guardian/factors/phone/message-types"))
+        .and(body_json(
+            serde_json::json!({ "message_types": ["sms", "voice"] }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "message_types": ["sms", "voice"]
+        })))
+        .mount(&server)
+        .await;
+
+    let guardian = client.guardian();
+    assert_eq!(
+        guardian.get_phone_provider().await.expect("get provider"),
+        PhoneProvider::Auth0
+    );
+    assert_eq!(
+        guardian
+            .set_phone_provider(PhoneProvider::PhoneMessageHook)
+            .await
+            .expect("set provider"),
+        PhoneProvider::PhoneMessageHook
+    );
+    assert_eq!(
+        guardian
+            .set_phone_message_types(vec![PhoneDeliveryMethod::Sms, PhoneDeliveryMethod::Voice])
+            .await
+            .expect("set message types"),
+        vec![PhoneDeliveryMethod::Sms, PhoneDeliveryMethod::Voice]
+    );
+}
+
+#[tokio::test]
+async fn test_twilio_config() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/guardian/factors/phone/providers/twilio"))
+        .and(body_json(serde_json::json!({
+            "messaging_service_sid": "MG123",
+            "sid": "AC123",
+            "auth_token": "twilio-token"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "from": null,
+            "messaging_service_sid": "MG123",
+            "sid": "AC123",
+            "auth_token": "twilio-token"
+        })))
+        .mount(&server)
+        .await;
+
+    let config = client
+        .guardian()
+        .set_twilio_config(TwilioConfig {
+            messaging_service_sid: Some("MG123".into()),
+            sid: Some("AC123".into()),
+            auth_token: Some("twilio-token".into()),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to set Twilio config");
+
+    assert!(config.from.is_none());
+    assert_eq!(
+        config.auth_token.as_ref().map(|t| t.expose_secret()),
+        Some("twilio-token")
+    );
+    assert!(!format!("{:?}", config).contains("twilio-token"));
+}
+
+#[tokio::test]
+async fn test_push_notification_config() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/api/v2/guardian/factors/push-notification/selected-provider",
+        ))
+        .and(body_json(serde_json::json!({ "provider": "sns" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "provider": "sns"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/api/v2/guardian/factors/push-notification/providers/sns",
+        ))
+        .and(body_json(serde_json::json!({
+            "aws_access_key_id": "AKIA123",
+            "aws_secret_access_key": "aws-secret",
+            "aws_region": "eu-west-1"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "aws_access_key_id": "AKIA123",
+            "aws_region": "eu-west-1",
+            "sns_apns_platform_application_arn": null,
+            "sns_gcm_platform_application_arn": null
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/api/v2/guardian/factors/push-notification/providers/apns",
+        ))
+        .and(body_json(serde_json::json!({
+            "sandbox": true,
+            "bundle_id": "com.example.app",
+            "p12": "base64-cert"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sandbox": true,
+            "bundle_id": "com.example.app"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let guardian = client.guardian();
+    assert_eq!(
+        guardian
+            .set_push_provider(PushProvider::Sns)
+            .await
+            .expect("set push provider"),
+        PushProvider::Sns
+    );
+
+    let sns = guardian
+        .set_sns_config(SnsConfig {
+            aws_access_key_id: Some("AKIA123".into()),
+            aws_secret_access_key: Some("aws-secret".into()),
+            aws_region: Some("eu-west-1".into()),
+            ..Default::default()
+        })
+        .await
+        .expect("set SNS config");
+    assert!(sns.aws_secret_access_key.is_none());
+    assert_eq!(sns.aws_region.as_deref(), Some("eu-west-1"));
+
+    guardian
+        .set_apns_config(ApnsConfig {
+            sandbox: Some(true),
+            bundle_id: Some("com.example.app".into()),
+            p12: Some("base64-cert".into()),
+        })
+        .await
+        .expect("set APNs config");
+}
+
+#[tokio::test]
+async fn test_create_enrollment_ticket() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/guardian/enrollments/ticket"))
+        .and(body_json(serde_json::json!({
+            "user_id": "auth0|123",
+            "send_mail": false,
+            "factor": "otp"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ticket_id": "tkt_1",
+            "ticket_url": "https://example.auth0.com/guardian/enroll?ticket=abc"
+        })))
+        .mount(&server)
+        .await;
+
+    let request = CreateEnrollmentTicketRequest {
+        send_mail: Some(false),
+        factor: Some(GuardianFactorName::Otp),
+        ..CreateEnrollmentTicketRequest::new(UserId::new("auth0|123"))
+    };
+    let ticket = client
+        .guardian()
+        .create_enrollment_ticket(request)
+        .await
+        .expect("Failed to create enrollment ticket");
+
+    assert_eq!(ticket.ticket_id, "tkt_1");
+    assert!(ticket.ticket_url.contains("ticket=abc"));
+}
+
+#[tokio::test]
+async fn test_get_and_delete_enrollment() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/guardian/enrollments/dev_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "dev_123",
+            "status": "confirmed",
+            "name": "iPhone",
+            "phone_number": "+1 555-0100"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/guardian/enrollments/dev_123"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let enrollment = client
+        .guardian()
+        .get_enrollment("dev_123")
+        .await
+        .expect("Failed to get enrollment");
+    assert_eq!(enrollment.status, Some(EnrollmentStatus::Confirmed));
+    assert_eq!(enrollment.name.as_deref(), Some("iPhone"));
+
+    client
+        .guardian()
+        .delete_enrollment("dev_123")
+        .await
+        .expect("Failed to delete enrollment");
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "hooks")]
+
+use std::collections::HashMap;
+
+use auth0_mgmt_api::{
+    CreateHookRequest, HookTrigger, ListHooksParams, ManagementClient, Patch, UpdateHookRequest,
+};
+use secrecy::SecretString;
+use wiremock::matchers::{bearer_token, body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+fn hook_json(id: &str, trigger: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "name": "add-locale",
+        "script": "module.exports = function (user, context, cb) { cb(null, { user }); };",
+        "triggerId": trigger,
+        "enabled": true,
+        "dependencies": { "lodash": "4.17.21" }
+    })
+}
+
+#[tokio::test]
+async fn test_list_hooks_by_trigger() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/hooks"))
+        .and(bearer_token("test_token"))
+        .and(query_param("triggerId", "credentials-exchange"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([hook_json(
+                "01GXYZ",
+                "credentials-exchange"
+            )])),
+        )
+        .mount(&server)
+        .await;
+
+    let params = ListHooksParams {
+        trigger_id: Some(HookTrigger::CredentialsExchange),
+        ..Default::default()
+    };
+    let hooks = client.hooks().list(Some(params)).await.unwrap();
+
+    assert_eq!(hooks.len(), 1);
+    assert_eq!(hooks[0].trigger_id, Some(HookTrigger::CredentialsExchange));
+    assert_eq!(hooks[0].dependencies["lodash"], "4.17.21");
+}
+
+#[tokio::test]
+async fn test_list_hooks_with_totals_and_unknown_trigger() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/hooks"))
+        .and(query_param("include_totals", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "hooks": [hook_json("01GXYZ", "post-login-legacy")],
+            "start": 0,
+            "limit": 50,
+            "total": 1
+        })))
+        .mount(&server)
+        .await;
+
+    let page = client.hooks().list_with_totals(None).await.unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(
+        page.hooks[0].trigger_id,
+        Some(HookTrigger::Other("post-login-legacy".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_create_update_delete_hook() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/hooks"))
+        .and(body_json(serde_json::json!({
+            "name": "add-locale",
+            "script": "module.exports = function (user, context, cb) { cb(null, { user }); };",
+            "triggerId": "pre-user-registration",
+            "enabled": true
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(hook_json("01GXYZ", "pre-user-registration")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/hooks/01GXYZ"))
+        .and(body_json(serde_json::json!({ "enabled": false })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "01GXYZ",
+            "name": "add-locale",
+            "triggerId": "pre-user-registration",
+            "enabled": false
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/hooks/01GXYZ"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = CreateHookRequest {
+        enabled: Some(true),
+        ..CreateHookRequest::new(
+            "add-locale",
+            "module.exports = function (user, context, cb) { cb(null, { user }); };",
+            HookTrigger::PreUserRegistration,
+        )
+    };
+    let hook = client.hooks().create(request).await.unwrap();
+    assert_eq!(hook.id, "01GXYZ");
+
+    let update = UpdateHookRequest {
+        enabled: Patch::Value(false),
+        ..Default::default()
+    };
+    let hook = client.hooks().update("01GXYZ", update).await.unwrap();
+    assert_eq!(hook.enabled, Some(false));
+    assert!(hook.dependencies.is_empty());
+
+    client.hooks().delete("01GXYZ").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_hook_secrets() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/hooks/01GXYZ/secrets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "API_KEY": "_VALUE_NOT_SHOWN_"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/hooks/01GXYZ/secrets"))
+        .and(body_json(serde_json::json!({ "API_KEY": "s3cret" })))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/hooks/01GXYZ/secrets"))
+        .and(body_json(serde_json::json!({ "API_KEY": "rotated" })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/hooks/01GXYZ/secrets"))
+        .and(body_json(serde_json::json!(["API_KEY"])))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let secrets = client.hooks().get_secrets("01GXYZ").await.unwrap();
+    assert!(secrets.contains_key("API_KEY"));
+
+    let mut secrets = HashMap::new();
+    secrets.insert("API_KEY".to_string(), SecretString::from("s3cret"));
+    client
+        .hooks()
+        .add_secrets("01GXYZ", &secrets)
+        .await
+        .unwrap();
+
+    secrets.insert("API_KEY".to_string(), SecretString::from("rotated"));
+    client
+        .hooks()
+        .update_secrets("01GXYZ", &secrets)
+        .await
+        .unwrap();
+
+    client
+        .hooks()
+        .delete_secrets("01GXYZ", &["API_KEY"])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "keys")]
+
+use auth0_mgmt_api::{Auth0Error, Jwks, ManagementClient, SigningKey};
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const CERT: &str = "-----BEGIN CERTIFICATE-----\nMIICFDCCAX2gAwIBAgIUXbmp1wEvN9mI64x1iPsl9Xxnpq4wDQYJKoZIhvcNAQEL\nBQAwHDEaMBgGA1UEAwwRZXhhbXBsZS5hdXRoMC5jb20wHhcNMjYxMDE4MTQwNTQ3\nWhcNMzYxMDE1MTQwNTQ3WjAcMRowGAYDVQQDDBFleGFtcGxlLmF1dGgwLmNvbTCB\nnzANBgkqhkiG9w0BAQEFAAOBjQAwgYkCgYEA5hqWQwoFYMop2xfeHLYftkNZUzVK\nUnLCS/dzeZRPAWb4PcAhBSGaXbbeDN2/TuXWUZlK/Fo7KMy3JsWTqCPC82viOyIr\nXDbPjWHpLz2XjoFdsO+iRiyCCZcyISowzpP7d9nFK/ZdS+4ND1KnEUXJFYMNuw5s\n4kFJadhOngKgpqUCAwEAAaNTMFEwHQYDVR0OBBYEFNUfnGkMz0fcdDU1f0ESPHkI\nZlGBMB8GA1UdIwQYMBaAFNUfnGkMz0fcdDU1f0ESPHkIZlGBMA8GA1UdEwEB/wQF\nMAMBAf8wDQYJKoZIhvcNAQELBQADgYEALuSt+9BrH+pXFgZQqzE13VZRdLnIT64q\ngXkl/XABjLO784sTtDFCVmo+5cWZf6SMWmVwUSvZ+V+G+aKgb5n7MbUm6s2r2ywF\ncScL2Qw2WJkyyn4VculpvVCa4+N5k5YUZB77cX17c8MzvVfJ8a1zbA0EzTnCcqeq\nQ28EmNsEPY0=\n-----END CERTIFICATE-----";
+
+const MODULUS: &str = "5hqWQwoFYMop2xfeHLYftkNZUzVKUnLCS_dzeZRPAWb4PcAhBSGaXbbeDN2_TuXWUZlK_Fo7KMy3JsWTqCPC82viOyIrXDbPjWHpLz2XjoFdsO-iRiyCCZcyISowzpP7d9nFK_ZdS-4ND1KnEUXJFYMNuw5s4kFJadhOngKgpqU";
+
+fn signing_key_json(kid: &str, current: bool, revoked: bool) -> serde_json::Value {
+    serde_json::json!({
+        "kid": kid,
+        "cert": CERT,
+        "pkcs7": "-----BEGIN PKCS7-----\n...\n-----END PKCS7-----",
+        "current": current,
+        "next": false,
+        "previous": !current,
+        "current_since": "2026-01-01T00:00:00.000Z",
+        "fingerprint": "F9:97:9E:9E:B7:5F:07:81:0F:7D:76:9D:3B:6D:AE:03:56:8C:4C:09",
+        "thumbprint": "f9979e9eb75f07810f7d769d3b6dae03568c4c09",
+        "revoked": revoked
+    })
+}
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_list_signing_keys() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/keys/signing"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            signing_key_json("key_current", true, false),
+            signing_key_json("key_previous", false, true)
+        ])))
+        .mount(&server)
+        .await;
+
+    let keys = client.keys().list().await.unwrap();
+    assert_eq!(keys.len(), 2);
+    assert!(keys[0].current);
+    assert!(keys[1].previous);
+    assert!(keys[1].revoked);
+    assert_eq!(
+        keys[0]
+            .pkcs7
+            .as_deref()
+            .map(|p| p.starts_with("-----BEGIN PKCS7")),
+        Some(true)
+    );
+}
+
+#[tokio::test]
+async fn test_get_signing_key() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/keys/signing/key_current"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(signing_key_json(
+            "key_current",
+            true,
+            false,
+        )))
+        .mount(&server)
+        .await;
+
+    let key = client.keys().get("key_current").await.unwrap();
+    assert_eq!(key.kid, "key_current");
+    assert_eq!(key.cert, CERT);
+    assert_eq!(
+        key.fingerprint.as_deref(),
+        Some("F9:97:9E:9E:B7:5F:07:81:0F:7D:76:9D:3B:6D:AE:03:56:8C:4C:09")
+    );
+}
+
+#[tokio::test]
+async fn test_rotate_and_revoke_signing_key() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/keys/signing/rotate"))
+        .and(body_json(serde_json::json!({})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "kid": "key_next",
+            "cert": CERT
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/keys/signing/key_previous/revoke"))
+        .and(body_json(serde_json::json!({})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "kid": "key_previous",
+            "cert": CERT
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let rotated = client.keys().rotate().await.unwrap();
+    assert_eq!(rotated.kid, "key_next");
+
+    let revoked = client.keys().revoke("key_previous").await.unwrap();
+    assert_eq!(revoked.kid, "key_previous");
+}
+
+#[test]
+fn test_signing_key_to_jwk() {
+    let key: SigningKey =
+        serde_json::from_value(signing_key_json("key_current", true, false)).unwrap();
+    let jwk = key.to_jwk().unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&jwk).unwrap()["use"],
+        serde_json::json!("sig")
+    );
+    assert_eq!(jwk.kty, "RSA");
+    assert_eq!(jwk.alg, "RS256");
+    assert_eq!(jwk.kid, "key_current");
+    assert_eq!(jwk.n, MODULUS);
+    assert_eq!(jwk.e, "AQAB");
+    assert_eq!(jwk.x5t, "-ZeenrdfB4EPfXadO22uA1aMTAk");
+
+    let body: String = CERT.lines().filter(|l| !l.starts_with("-----")).collect();
+    assert_eq!(jwk.x5c, vec![body]);
+}
+
+#[test]
+fn test_signing_key_to_jwk_invalid_certificate() {
+    let mut key: SigningKey =
+        serde_json::from_value(signing_key_json("key_current", true, false)).unwrap();
+
+    key.cert = "not a certificate".to_string();
+    assert!(matches!(
+        key.to_jwk(),
+        Err(Auth0Error::InvalidCertificate(_))
+    ));
+
+    key.cert = "-----BEGIN CERTIFICATE-----\nMAA=\n-----END CERTIFICATE-----".to_string();
+    assert!(matches!(
+        key.to_jwk(),
+        Err(Auth0Error::InvalidCertificate(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_jwks_skips_revoked_keys() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/keys/signing"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            signing_key_json("key_current", true, false),
+            signing_key_json("key_previous", false, true)
+        ])))
+        .mount(&server)
+        .await;
+
+    let jwks = client.keys().jwks().await.unwrap();
+    assert_eq!(jwks.keys.len(), 1);
+    assert_eq!(jwks.keys[0].kid, "key_current");
+    assert_eq!(jwks.keys[0].n, MODULUS);
+
+    let json = serde_json::to_value(&jwks).unwrap();
+    let parsed: Jwks = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, jwks);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "log-receiver")]
+
+use auth0_mgmt_api::Auth0Error;
+use auth0_mgmt_api::log_receiver::{LogStreamReceiver, decode_body};
+
+#[test]
+fn test_decode_json_array_of_wrapped_events() {
+    let body = r#"[
+        {
+            "log_id": "900201",
+            "data": { "type": "s", "date": "2023-11-15T10:30:00.000Z", "user_id": "auth0|1" }
+        },
+        {
+            "log_id": "900202",
+            "data": { "log_id": "900202", "type": "f", "description": "Wrong password" }
+        }
+    ]"#;
+
+    let events = decode_body(body.as_bytes()).expect("Failed to decode array body");
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].log_id, "900201");
+    assert_eq!(events[0].event_type, "s");
+    assert_eq!(events[0].user_id.as_deref(), Some("auth0|1"));
+    assert_eq!(events[1].description.as_deref(), Some("Wrong password"));
+}
+
+#[test]
+fn test_decode_json_lines_and_bare_events() {
+    let body = concat!(
+        r#"{"log_id":"1","data":{"type":"s"}}"#,
+        "\n",
+        r#"{"log_id":"2","type":"fp"}"#,
+        "\n\n"
+    );
+
+    let events = decode_body(body.as_bytes()).expect("Failed to decode JSON lines body");
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].log_id, "1");
+    assert_eq!(events[1].event_type, "fp");
+}
+
+#[test]
+fn test_decode_rejects_invalid_body() {
+    let result = decode_body(b"{\"log_id\": \"1\", ");
+    assert!(matches!(result, Err(Auth0Error::Json(_))));
+
+    let missing_type = decode_body(br#"[{"log_id": "1", "data": {}}]"#);
+    assert!(matches!(missing_type, Err(Auth0Error::Json(_))));
+}
+
+#[test]
+fn test_decode_checks_authorization() {
+    let receiver = LogStreamReceiver::with_authorization("Bearer secret");
+    let body = br#"[{"log_id":"1","data":{"type":"s"}}]"#;
+
+    assert!(receiver.decode(Some("Bearer secret"), body).is_ok());
+    assert!(matches!(
+        receiver.decode(Some("Bearer wrong"), body),
+        Err(Auth0Error::Authentication { .. })
+    ));
+    assert!(matches!(
+        receiver.decode(None, body),
+        Err(Auth0Error::Authentication { .. })
+    ));
+
+    assert!(LogStreamReceiver::new().decode(None, body).is_ok());
+}
+
+#[tokio::test]
+async fn test_receive_http_request() {
+    let receiver = LogStreamReceiver::with_authorization("Bearer secret");
+
+    let request = http::Request::builder()
+        .method("POST")
+        .uri("/auth0/logs")
+        .header("authorization", "Bearer secret")
+        .body(r#"{"log_id":"1","data":{"type":"s"}}"#.to_string())
+        .unwrap();
+    let result = receiver.receive(request).await;
+
+    let events = result.as_ref().expect("Failed to receive request");
+    assert_eq!(events[0].log_id, "1");
+    assert_eq!(
+        LogStreamReceiver::response(&result).status(),
+        http::StatusCode::OK
+    );
+
+    let unauthorized = http::Request::builder()
+        .method("POST")
+        .uri("/auth0/logs")
+        .body("[]".to_string())
+        .unwrap();
+    let result = receiver.receive(unauthorized).await;
+    assert_eq!(
+        LogStreamReceiver::response(&result).status(),
+        http::StatusCode::UNAUTHORIZED
+    );
+
+    let malformed = http::Request::builder()
+        .method("POST")
+        .uri("/auth0/logs")
+        .header("authorization", "Bearer secret")
+        .body("not json".to_string())
+        .unwrap();
+    let result = receiver.receive(malformed).await;
+    assert_eq!(
+        LogStreamReceiver::response(&result).status(),
+        http::StatusCode::BAD_REQUEST
+    );
+}
+
+#[tokio::test]
+async fn test_receive_rejects_body_over_limit() {
+    let receiver = LogStreamReceiver::new().with_max_body_size(16);
+
+    let oversized = http::Request::builder()
+        .method("POST")
+        .uri("/auth0/logs")
+        .body(r#"[{"log_id":"1","data":{"type":"s"}}]"#.to_string())
+        .unwrap();
+    let result = receiver.receive(oversized).await;
+
+    match &result {
+        Err(Auth0Error::OperationFailed(message)) => assert!(message.contains("16 bytes")),
+        other => panic!("Expected OperationFailed, got {:?}", other),
+    }
+
+    let within_limit = http::Request::builder()
+        .method("POST")
+        .uri("/auth0/logs")
+        .body("[]".to_string())
+        .unwrap();
+    let events = receiver
+        .receive(within_limit)
+        .await
+        .expect("Failed to receive small body");
+    assert!(events.is_empty());
+}
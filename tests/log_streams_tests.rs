@@ -0,0 +1,381 @@
+#![cfg(feature = "log_streams")]
+
+use auth0_mgmt_api::{
+    CreateLogStreamRequest, DatadogRegion, DatadogSink, HttpContentFormat, HttpSink,
+    LogEventCategory, LogStreamFilter, LogStreamSink, LogStreamStatus, ManagementClient, Patch,
+    PiiConfig, PiiLogField, PiiMethod, SumoSink, UpdateLogStreamRequest,
+};
+use secrecy::ExposeSecret;
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_list_log_streams() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/log-streams"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "id": "lst_1",
+                "name": "webhook",
+                "type": "http",
+                "status": "active",
+                "sink": {
+                    "httpEndpoint": "https://logs.example.com/auth0",
+                    "httpContentFormat": "JSONLINES",
+                    "httpAuthorization": "Bearer abc",
+                    "httpCustomHeaders": [{ "header": "X-Env", "value": "prod" }]
+                },
+                "filters": [{ "type": "category", "name": "auth.login.fail" }],
+                "isPriority": false
+            },
+            {
+                "id": "lst_2",
+                "name": "events",
+                "type": "eventbridge",
+                "status": "suspended",
+                "sink": {
+                    "awsAccountId": "123456789012",
+                    "awsRegion": "eu-west-1",
+                    "awsPartnerEventSource": "aws.partner/auth0.com/tenant/source"
+                }
+            },
+            {
+                "id": "lst_3",
+                "name": "future",
+                "type": "newsink",
+                "status": "paused",
+                "sink": { "whatever": true }
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let streams = client
+        .log_streams()
+        .list()
+        .await
+        .expect("Failed to list log streams");
+
+    assert_eq!(streams.len(), 3);
+    match &streams[0].sink {
+        LogStreamSink::Http(sink) => {
+            assert_eq!(sink.http_content_format, Some(HttpContentFormat::JsonLines));
+            assert_eq!(
+                sink.http_authorization.as_ref().map(|s| s.expose_secret()),
+                Some("Bearer abc")
+            );
+            assert_eq!(sink.http_custom_headers[0].header, "X-Env");
+        }
+        other => panic!("Expected http sink, got {:?}", other),
+    }
+    assert_eq!(
+        streams[0].filters,
+        vec![LogStreamFilter::category(LogEventCategory::AuthLoginFail)]
+    );
+    assert_eq!(streams[1].status, Some(LogStreamStatus::Suspended));
+    assert!(matches!(
+        &streams[1].sink,
+        LogStreamSink::Eventbridge(sink) if sink.aws_partner_event_source.is_some()
+    ));
+    assert!(matches!(
+        &streams[2].sink,
+        LogStreamSink::Other { stream_type, sink } if stream_type == "newsink" && sink["whatever"] == true
+    ));
+}
+
+#[tokio::test]
+async fn test_create_log_stream() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/log-streams"))
+        .and(body_json(serde_json::json!({
+            "name": "datadog-prod",
+            "type": "datadog",
+            "sink": { "datadogRegion": "eu", "datadogApiKey": "dd-key" },
+            "filters": [{ "type": "category", "name": "management.fail" }]
+        })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "lst_4",
+            "name": "datadog-prod",
+            "type": "datadog",
+            "status": "active",
+            "sink": { "datadogRegion": "eu", "datadogApiKey": "dd-key" },
+            "filters": [{ "type": "category", "name": "management.fail" }]
+        })))
+        .mount(&server)
+        .await;
+
+    let sink = LogStreamSink::Datadog(DatadogSink {
+        datadog_region: DatadogRegion::Eu,
+        datadog_api_key: Some("dd-key".into()),
+    });
+    let mut request = CreateLogStreamRequest::new("datadog-prod", sink);
+    request.filters = Some(vec![LogStreamFilter::category(
+        LogEventCategory::ManagementFail,
+    )]);
+
+    let stream = client
+        .log_streams()
+        .create(request)
+        .await
+        .expect("Failed to create log stream");
+
+    assert_eq!(stream.id, "lst_4");
+    assert!(matches!(stream.sink, LogStreamSink::Datadog(_)));
+}
+
+#[tokio::test]
+async fn test_update_log_stream_sends_sink_config_only() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/log-streams/lst_1"))
+        .and(body_json(serde_json::json!({
+            "status": "active",
+            "sink": { "httpEndpoint": "https://logs.example.com/v2" }
+        })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "lst_1",
+            "type": "http",
+            "status": "active",
+            "sink": { "httpEndpoint": "https://logs.example.com/v2" }
+        })))
+        .mount(&server)
+        .await;
+
+    let update = UpdateLogStreamRequest {
+        status: Patch::Value(LogStreamStatus::Active),
+        sink: Patch::Value(LogStreamSink::Http(HttpSink {
+            http_endpoint: "https://logs.example.com/v2".to_string(),
+            http_content_type: None,
+            http_content_format: None,
+            http_authorization: None,
+            http_custom_headers: Vec::new(),
+        })),
+        ..Default::default()
+    };
+
+    let stream = client
+        .log_streams()
+        .update("lst_1", update)
+        .await
+        .expect("Failed to update log stream");
+
+    assert_eq!(stream.status, Some(LogStreamStatus::Active));
+}
+
+#[tokio::test]
+async fn test_get_and_delete_log_stream() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/log-streams/lst_5"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "lst_5",
+            "type": "sumo",
+            "sink": { "sumoSourceAddress": "https://endpoint.sumologic.com/x" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/log-streams/lst_5"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let stream = client
+        .log_streams()
+        .get("lst_5")
+        .await
+        .expect("Failed to get log stream");
+    assert!(matches!(stream.sink, LogStreamSink::Sumo(_)));
+    assert!(stream.filters.is_empty());
+
+    client
+        .log_streams()
+        .delete("lst_5")
+        .await
+        .expect("Failed to delete log stream");
+}
+
+#[tokio::test]
+async fn test_get_log_stream_with_unknown_filter_category() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/log-streams/lst_5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "lst_5",
+            "name": "sumo",
+            "type": "sumo",
+            "sink": { "sumoSourceAddress": "https://collectors.sumologic.com/x" },
+            "filters": [
+                { "type": "category", "name": "other" },
+                { "type": "category", "name": "auth.passkey.success" }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let stream = client
+        .log_streams()
+        .get("lst_5")
+        .await
+        .expect("Failed to get log stream");
+
+    assert_eq!(
+        stream.filters,
+        vec![
+            LogStreamFilter::category(LogEventCategory::Other),
+            LogStreamFilter::category(LogEventCategory::Unknown(
+                "auth.passkey.success".to_string()
+            )),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_log_stream_without_secret_keeps_typed_sink() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/log-streams/lst_6"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "lst_6",
+            "name": "splunk",
+            "type": "splunk",
+            "sink": { "splunkDomain": "splunk.example.com", "splunkSecure": true }
+        })))
+        .mount(&server)
+        .await;
+
+    let stream = client
+        .log_streams()
+        .get("lst_6")
+        .await
+        .expect("Failed to get log stream");
+
+    match stream.sink {
+        LogStreamSink::Splunk(sink) => {
+            assert_eq!(sink.splunk_domain, "splunk.example.com");
+            assert!(sink.splunk_token.is_none());
+        }
+        other => panic!("Expected splunk sink, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_update_log_stream_clears_filters_and_pii_config() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/log-streams/lst_1"))
+        .and(body_json(serde_json::json!({
+            "filters": null,
+            "pii_config": null
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "lst_1",
+            "type": "sumo",
+            "sink": { "sumoSourceAddress": "https://collectors.sumologic.com/x" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let update = UpdateLogStreamRequest {
+        filters: Patch::Null,
+        pii_config: Patch::Null,
+        ..Default::default()
+    };
+
+    let stream = client
+        .log_streams()
+        .update("lst_1", update)
+        .await
+        .expect("Failed to update log stream");
+
+    assert!(stream.filters.is_empty());
+    assert!(stream.pii_config.is_none());
+}
+
+#[tokio::test]
+async fn test_create_log_stream_with_pii_config() {
+    let (server, client) = setup_mock_server().await;
+
+    let pii_config = serde_json::json!({
+        "log_fields": ["email", "phone"],
+        "method": "hash",
+        "algorithm": "xxhash"
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/log-streams"))
+        .and(body_json(serde_json::json!({
+            "name": "sumo",
+            "type": "sumo",
+            "sink": { "sumoSourceAddress": "https://collectors.sumologic.com/x" },
+            "pii_config": pii_config
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "lst_7",
+            "name": "sumo",
+            "type": "sumo",
+            "sink": { "sumoSourceAddress": "https://collectors.sumologic.com/x" },
+            "pii_config": pii_config
+        })))
+        .mount(&server)
+        .await;
+
+    let sink = LogStreamSink::Sumo(SumoSink {
+        sumo_source_address: "https://collectors.sumologic.com/x".to_string(),
+    });
+    let mut request = CreateLogStreamRequest::new("sumo", sink);
+    request.pii_config = Some(PiiConfig {
+        log_fields: vec![PiiLogField::Email, PiiLogField::Phone],
+        method: Some(PiiMethod::Hash),
+        algorithm: Some("xxhash".to_string()),
+    });
+
+    let stream = client
+        .log_streams()
+        .create(request)
+        .await
+        .expect("Failed to create log stream");
+
+    let pii = stream.pii_config.expect("Expected pii_config");
+    assert_eq!(pii.log_fields, vec![PiiLogField::Email, PiiLogField::Phone]);
+    assert_eq!(pii.method, Some(PiiMethod::Hash));
+}
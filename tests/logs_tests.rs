@@ -110,6 +110,66 @@ async fn test_list_logs_with_params() {
     assert_eq!(logs[0].log_id, "test_log_id");
 }
 
+#[cfg(not(feature = "chrono"))]
+#[tokio::test]
+async fn test_list_logs_with_date_range() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/logs"))
+        .and(query_param(
+            "q",
+            "(type:f) AND date:[2023-11-01T00:00:00.000Z TO *]",
+        ))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&server)
+        .await;
+
+    let params = ListLogsParams {
+        q: Some("type:f".to_string()),
+        ..Default::default()
+    }
+    .date_range(auth0_mgmt_api::DateRange::new().from("2023-11-01T00:00:00.000Z"));
+
+    let logs = client
+        .logs()
+        .list(Some(params))
+        .await
+        .expect("Failed to list logs with date range");
+
+    assert!(logs.is_empty());
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_log_date_parsed_as_timestamp() {
+    use chrono::{Datelike, Timelike};
+
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/logs/log_123"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "log_id": "log_123",
+            "type": "s",
+            "date": "2023-11-15T10:30:00.000Z"
+        })))
+        .mount(&server)
+        .await;
+
+    let log = client
+        .logs()
+        .get("log_123")
+        .await
+        .expect("Failed to get log");
+
+    let date = log.date.expect("date should be present");
+    assert_eq!(date.year(), 2023);
+    assert_eq!(date.hour(), 10);
+}
+
 #[tokio::test]
 async fn test_list_logs_with_checkpoint() {
     let (server, client) = setup_mock_server().await;
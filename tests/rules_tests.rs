@@ -0,0 +1,220 @@
+#![cfg(feature = "rules")]
+
+use auth0_mgmt_api::{
+    CreateRuleRequest, ListRulesParams, ManagementClient, Patch, RuleId, RuleStage,
+    UpdateRuleRequest,
+};
+use wiremock::matchers::{bearer_token, body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_list_rules() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/rules"))
+        .and(query_param("enabled", "true"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "id": "rul_1",
+                "name": "add-country",
+                "script": "function (user, context, callback) {}",
+                "order": 1,
+                "enabled": true,
+                "stage": "login_success"
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let params = ListRulesParams {
+        enabled: Some(true),
+        ..Default::default()
+    };
+    let rules = client
+        .rules()
+        .list(Some(params))
+        .await
+        .expect("Failed to list rules");
+
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].id, RuleId::new("rul_1"));
+    assert_eq!(rules[0].order, Some(1));
+    assert_eq!(rules[0].stage, Some(RuleStage::LoginSuccess));
+}
+
+#[tokio::test]
+async fn test_list_rules_with_totals() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/rules"))
+        .and(query_param("include_totals", "true"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "rules": [{ "id": "rul_1", "name": "add-country" }],
+            "start": 0,
+            "limit": 50,
+            "total": 1
+        })))
+        .mount(&server)
+        .await;
+
+    let page = client
+        .rules()
+        .list_with_totals(None)
+        .await
+        .expect("Failed to list rules with totals");
+
+    assert_eq!(page.total, 1);
+    assert_eq!(page.rules[0].name.as_deref(), Some("add-country"));
+}
+
+#[tokio::test]
+async fn test_create_update_delete_rule() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/rules"))
+        .and(body_json(serde_json::json!({
+            "name": "add-country",
+            "script": "function (user, context, callback) {}",
+            "order": 2,
+            "stage": "login_success"
+        })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "rul_2",
+            "name": "add-country",
+            "order": 2,
+            "enabled": true
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/rules/rul_2"))
+        .and(body_json(serde_json::json!({ "enabled": false })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "rul_2",
+            "enabled": false
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/rules/rul_2"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = CreateRuleRequest {
+        order: Some(2),
+        stage: Some(RuleStage::LoginSuccess),
+        ..CreateRuleRequest::new("add-country", "function (user, context, callback) {}")
+    };
+    let created = client
+        .rules()
+        .create(request)
+        .await
+        .expect("Failed to create rule");
+    assert_eq!(created.id.as_str(), "rul_2");
+
+    let update = UpdateRuleRequest {
+        enabled: Patch::Value(false),
+        ..Default::default()
+    };
+    let updated = client
+        .rules()
+        .update(created.id.clone(), update)
+        .await
+        .expect("Failed to update rule");
+    assert_eq!(updated.enabled, Some(false));
+
+    client
+        .rules()
+        .delete(created.id)
+        .await
+        .expect("Failed to delete rule");
+}
+
+#[tokio::test]
+async fn test_rules_configs() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/rules-configs"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            { "key": "SLACK_HOOK" }
+        ])))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v2/rules-configs/SLACK_HOOK"))
+        .and(body_json(
+            serde_json::json!({ "value": "https://hooks.example.com/abc" }),
+        ))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "key": "SLACK_HOOK",
+            "value": "https://hooks.example.com/abc"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/rules-configs/SLACK_HOOK"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let keys = client
+        .rules_configs()
+        .list()
+        .await
+        .expect("Failed to list rules configs");
+    assert_eq!(keys[0].key, "SLACK_HOOK");
+
+    let set = client
+        .rules_configs()
+        .set("SLACK_HOOK", "https://hooks.example.com/abc".into())
+        .await
+        .expect("Failed to set rules config");
+    assert_eq!(set.key, "SLACK_HOOK");
+
+    client
+        .rules_configs()
+        .delete("SLACK_HOOK")
+        .await
+        .expect("Failed to delete rules config");
+}
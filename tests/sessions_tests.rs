@@ -0,0 +1,238 @@
+use auth0_mgmt_api::{CheckpointParams, ManagementClient, UserId};
+use wiremock::matchers::{bearer_token, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_list_user_sessions() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/users/auth0%7C123/sessions"))
+        .and(query_param("take", "2"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sessions": [
+                {
+                    "id": "sess_1",
+                    "user_id": "auth0|123",
+                    "created_at": "2023-11-15T10:30:00.000Z",
+                    "last_interacted_at": "2023-11-15T11:00:00.000Z",
+                    "expires_at": "2023-11-18T10:30:00.000Z",
+                    "device": {
+                        "initial_ip": "10.0.0.1",
+                        "last_user_agent": "Mozilla/5.0"
+                    },
+                    "clients": [{ "client_id": "client_abc" }],
+                    "authentication": {
+                        "methods": [
+                            { "name": "pwd", "timestamp": "2023-11-15T10:30:00.000Z" },
+                            { "name": "mfa", "type": "otp" }
+                        ]
+                    }
+                }
+            ],
+            "next": "checkpoint_2"
+        })))
+        .mount(&server)
+        .await;
+
+    let params = CheckpointParams {
+        take: Some(2),
+        ..Default::default()
+    };
+    let page = client
+        .users()
+        .list_sessions(UserId::new("auth0|123"), Some(params))
+        .await
+        .expect("Failed to list sessions");
+
+    assert_eq!(page.next.as_deref(), Some("checkpoint_2"));
+    let session = &page.sessions[0];
+    assert_eq!(session.clients[0].client_id.as_str(), "client_abc");
+    let device = session.device.as_ref().expect("Expected device");
+    assert_eq!(device.initial_ip.as_deref(), Some("10.0.0.1"));
+    let methods = &session.authentication.as_ref().unwrap().methods;
+    assert_eq!(methods[1].method_type.as_deref(), Some("otp"));
+}
+
+#[tokio::test]
+async fn test_delete_user_sessions_and_refresh_tokens() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/users/auth0%7C123/sessions"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/users/auth0%7C123/refresh-tokens"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let id = UserId::new("auth0|123");
+    client
+        .users()
+        .delete_sessions(id.clone())
+        .await
+        .expect("Failed to delete sessions");
+    client
+        .users()
+        .delete_refresh_tokens(id)
+        .await
+        .expect("Failed to delete refresh tokens");
+}
+
+#[tokio::test]
+async fn test_list_user_refresh_tokens() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/users/auth0%7C123/refresh-tokens"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "tokens": [
+                {
+                    "id": "rt_1",
+                    "user_id": "auth0|123",
+                    "client_id": "client_abc",
+                    "session_id": "sess_1",
+                    "rotating": true,
+                    "resource_servers": [
+                        { "audience": "https://api.example.com", "scopes": "read:items" }
+                    ]
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let page = client
+        .users()
+        .list_refresh_tokens(UserId::new("auth0|123"), None)
+        .await
+        .expect("Failed to list refresh tokens");
+
+    assert!(page.next.is_none());
+    let token = &page.tokens[0];
+    assert!(token.rotating);
+    assert_eq!(token.session_id.as_deref(), Some("sess_1"));
+    assert_eq!(
+        token.resource_servers[0].audience,
+        "https://api.example.com"
+    );
+}
+
+#[tokio::test]
+async fn test_get_and_delete_session() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/sessions/sess_1"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "sess_1",
+            "user_id": "auth0|123"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/sessions/sess_1"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/sessions/sess_1/revoke"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let session = client
+        .sessions()
+        .get("sess_1")
+        .await
+        .expect("Failed to get session");
+    assert_eq!(session.user_id, Some(UserId::new("auth0|123")));
+    assert!(session.clients.is_empty());
+
+    client
+        .sessions()
+        .delete("sess_1")
+        .await
+        .expect("Failed to delete session");
+    client
+        .sessions()
+        .revoke("sess_1")
+        .await
+        .expect("Failed to revoke session");
+}
+
+#[tokio::test]
+async fn test_get_and_delete_refresh_token() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/refresh-tokens/rt_1"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "rt_1",
+            "client_id": "client_abc"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/refresh-tokens/rt_1"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let token = client
+        .refresh_tokens()
+        .get("rt_1")
+        .await
+        .expect("Failed to get refresh token");
+    assert!(!token.rotating);
+
+    client
+        .refresh_tokens()
+        .delete("rt_1")
+        .await
+        .expect("Failed to delete refresh token");
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "tenants")]
+
+use auth0_mgmt_api::{
+    DeviceFlowCharset, ManagementClient, Patch, SessionCookie, SessionCookieMode, TenantFlags,
+    UpdateTenantSettingsRequest,
+};
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_get_tenant_settings() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/tenants/settings"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "friendly_name": "Example Corp",
+            "support_email": "support@example.com",
+            "default_audience": "https://api.example.com",
+            "allowed_logout_urls": ["https://example.com/logout"],
+            "session_lifetime": 168,
+            "idle_session_lifetime": 72.5,
+            "flags": {
+                "enable_client_connections": false,
+                "revoke_refresh_token_grant": true,
+                "some_new_flag": true
+            },
+            "session_cookie": { "mode": "non-persistent" },
+            "device_flow": { "charset": "base20", "mask": "****-****" },
+            "error_page": { "html": "", "show_log_link": false, "url": "https://example.com/error" }
+        })))
+        .mount(&server)
+        .await;
+
+    let settings = client
+        .tenant_settings()
+        .get()
+        .await
+        .expect("Failed to get tenant settings");
+
+    assert_eq!(settings.friendly_name.as_deref(), Some("Example Corp"));
+    assert_eq!(
+        settings.allowed_logout_urls,
+        vec!["https://example.com/logout"]
+    );
+    assert_eq!(settings.session_lifetime, Some(168.0));
+    assert_eq!(settings.idle_session_lifetime, Some(72.5));
+
+    let flags = settings.flags.expect("flags should be present");
+    assert_eq!(flags.enable_client_connections, Some(false));
+    assert_eq!(flags.revoke_refresh_token_grant, Some(true));
+    assert_eq!(flags.extra["some_new_flag"], true);
+
+    assert_eq!(
+        settings.session_cookie.map(|c| c.mode),
+        Some(SessionCookieMode::NonPersistent)
+    );
+    let device_flow = settings.device_flow.expect("device_flow should be present");
+    assert_eq!(device_flow.charset, Some(DeviceFlowCharset::Base20));
+    assert_eq!(device_flow.mask.as_deref(), Some("****-****"));
+    assert_eq!(
+        settings.error_page.and_then(|p| p.url).as_deref(),
+        Some("https://example.com/error")
+    );
+}
+
+#[tokio::test]
+async fn test_update_tenant_settings() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/tenants/settings"))
+        .and(body_json(serde_json::json!({
+            "default_audience": null,
+            "session_lifetime": 72.0,
+            "flags": { "enable_client_connections": false },
+            "session_cookie": { "mode": "persistent" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "session_lifetime": 72,
+            "flags": { "enable_client_connections": false },
+            "session_cookie": { "mode": "persistent" }
+        })))
+        .mount(&server)
+        .await;
+
+    let request = UpdateTenantSettingsRequest {
+        default_audience: Patch::Null,
+        session_lifetime: Patch::Value(72.0),
+        flags: Patch::Value(TenantFlags {
+            enable_client_connections: Some(false),
+            ..Default::default()
+        }),
+        session_cookie: Patch::Value(SessionCookie {
+            mode: SessionCookieMode::Persistent,
+        }),
+        ..Default::default()
+    };
+    let settings = client
+        .tenant_settings()
+        .update(request)
+        .await
+        .expect("Failed to update tenant settings");
+
+    assert_eq!(settings.session_lifetime, Some(72.0));
+    assert!(settings.allowed_logout_urls.is_empty());
+}
@@ -0,0 +1,119 @@
+use auth0_mgmt_api::{ManagementClient, UserId};
+use wiremock::matchers::{bearer_token, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup_mock_server() -> (MockServer, ManagementClient) {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test_token",
+            "expires_in": 86400,
+            "token_type": "Bearer"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = ManagementClient::builder()
+        .domain(server.uri())
+        .client_id("test_client_id")
+        .client_secret("test_client_secret")
+        .build()
+        .expect("Failed to build client");
+
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_get_user_blocks_by_identifier() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/user-blocks"))
+        .and(query_param("identifier", "user+tag@example.com"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "blocked_for": [
+                {
+                    "identifier": "user+tag@example.com",
+                    "ip": "10.0.0.1",
+                    "connection": "Username-Password-Authentication",
+                    "timestamp": "2023-11-15T10:30:00.000Z"
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let blocks = client
+        .user_blocks()
+        .get_by_identifier("user+tag@example.com")
+        .await
+        .expect("Failed to get user blocks");
+
+    assert!(blocks.is_blocked());
+    assert_eq!(blocks.blocked_for[0].ip.as_deref(), Some("10.0.0.1"));
+    assert!(blocks.blocked_for[0].timestamp.is_some());
+}
+
+#[tokio::test]
+async fn test_unblock_by_identifier() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/user-blocks"))
+        .and(query_param("identifier", "user@example.com"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .user_blocks()
+        .unblock_by_identifier("user@example.com")
+        .await
+        .expect("Failed to unblock identifier");
+}
+
+#[tokio::test]
+async fn test_get_user_blocks_by_user_id() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/user-blocks/auth0%7C123"))
+        .and(bearer_token("test_token"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "blocked_for": [] })),
+        )
+        .mount(&server)
+        .await;
+
+    let blocks = client
+        .user_blocks()
+        .get(UserId::new("auth0|123"))
+        .await
+        .expect("Failed to get user blocks");
+
+    assert!(!blocks.is_blocked());
+}
+
+#[tokio::test]
+async fn test_unblock_user() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/user-blocks/auth0%7C123"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .user_blocks()
+        .unblock(UserId::new("auth0|123"))
+        .await
+        .expect("Failed to unblock user");
+}
@@ -1,7 +1,12 @@
+use auth0_mgmt_api::types::PaginationParams;
 use auth0_mgmt_api::{
-    CreateUserRequest, GetUserLogsParams, ListUsersParams, ManagementClient, UpdateUserRequest,
+    AuthenticationMethod, ConnectionStrategy, CreateAuthenticationMethodRequest, CreateUserRequest,
+    EnrollmentStatus, GetUserLogsParams, LinkIdentityRequest, ListUsersParams, ManagementClient,
+    Metadata, MetadataPatch, Patch, PhoneDeliveryMethod, TypedCreateUserRequest, UpdateUserRequest,
     UserId,
 };
+use secrecy::ExposeSecret;
+use serde::{Deserialize, Serialize};
 use wiremock::matchers::{bearer_token, body_json, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -263,9 +268,9 @@ async fn test_update_user() {
     });
 
     let request = UpdateUserRequest {
-        email: Some("updated@example.com".to_string()),
-        name: Some("Updated User".to_string()),
-        blocked: Some(false),
+        email: Patch::Value("updated@example.com".to_string()),
+        name: Patch::Value("Updated User".to_string()),
+        blocked: Patch::Value(false),
         ..Default::default()
     };
 
@@ -508,6 +513,7 @@ async fn test_get_user_logs_with_params() {
         .mount(&server)
         .await;
 
+    #[allow(clippy::needless_update)]
     let params = GetUserLogsParams {
         page: Some(0),
         per_page: Some(10),
@@ -567,3 +573,610 @@ async fn test_get_user_logs_not_found() {
 
     assert!(result.is_err());
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct AppMeta {
+    plan: String,
+    roles: Vec<String>,
+}
+
+#[tokio::test]
+async fn test_get_user_typed_metadata() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/users/auth0%7C123456789"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user_id": "auth0|123456789",
+            "app_metadata": { "plan": "pro", "roles": ["admin"] },
+            "user_metadata": { "theme": "dark" }
+        })))
+        .mount(&server)
+        .await;
+
+    let user = client
+        .users()
+        .get_typed::<AppMeta, Metadata>(UserId::new("auth0|123456789"))
+        .await
+        .expect("Failed to get typed user");
+
+    assert_eq!(
+        user.app_metadata,
+        Some(AppMeta {
+            plan: "pro".to_string(),
+            roles: vec!["admin".to_string()],
+        })
+    );
+    assert_eq!(
+        user.user_metadata.unwrap().get("theme"),
+        Some(&serde_json::json!("dark"))
+    );
+}
+
+#[tokio::test]
+async fn test_user_app_metadata_as() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/users/auth0%7C123456789"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user_id": "auth0|123456789",
+            "app_metadata": { "plan": "free", "roles": [] }
+        })))
+        .mount(&server)
+        .await;
+
+    let user = client
+        .users()
+        .get(UserId::new("auth0|123456789"))
+        .await
+        .expect("Failed to get user");
+
+    let meta: Option<AppMeta> = user.app_metadata_as().expect("Failed to decode metadata");
+    assert_eq!(meta.unwrap().plan, "free");
+    assert!(user.user_metadata_as::<AppMeta>().unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_create_user_typed_metadata() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/users"))
+        .and(bearer_token("test_token"))
+        .and(body_json(serde_json::json!({
+            "connection": "Username-Password-Authentication",
+            "email": "typed@example.com",
+            "app_metadata": { "plan": "pro", "roles": ["admin"] }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "user_id": "auth0|typed",
+            "app_metadata": { "plan": "pro", "roles": ["admin"] }
+        })))
+        .mount(&server)
+        .await;
+
+    let request: TypedCreateUserRequest<AppMeta, Metadata> = TypedCreateUserRequest {
+        connection: "Username-Password-Authentication".to_string(),
+        email: Some("typed@example.com".to_string()),
+        app_metadata: Some(AppMeta {
+            plan: "pro".to_string(),
+            roles: vec!["admin".to_string()],
+        }),
+        ..Default::default()
+    };
+
+    let user = client
+        .users()
+        .create_typed(request)
+        .await
+        .expect("Failed to create typed user");
+
+    assert_eq!(user.app_metadata.unwrap().roles, vec!["admin".to_string()]);
+}
+
+#[test]
+fn test_metadata_from_typed_rejects_non_object() {
+    assert!(Metadata::from_typed(&"not an object").is_err());
+
+    let meta = Metadata::from_typed(&AppMeta {
+        plan: "pro".to_string(),
+        roles: vec![],
+    })
+    .expect("Failed to build metadata");
+    assert_eq!(meta.get("plan"), Some(&serde_json::json!("pro")));
+}
+
+#[tokio::test]
+async fn test_patch_metadata_sends_delta() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/users/auth0%7C123456789"))
+        .and(bearer_token("test_token"))
+        .and(body_json(serde_json::json!({
+            "app_metadata": { "plan": "pro", "trial": null }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user_id": "auth0|123456789",
+            "app_metadata": { "plan": "pro" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let patch = MetadataPatch::new().set("plan", "pro").remove("trial");
+    let user = client
+        .users()
+        .patch_metadata(UserId::new("auth0|123456789"), Some(patch), None)
+        .await
+        .expect("Failed to patch metadata");
+
+    assert_eq!(user.user_id, "auth0|123456789");
+}
+
+#[tokio::test]
+async fn test_patch_metadata_nested_preserves_siblings() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/users/auth0%7C123456789"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user_id": "auth0|123456789",
+            "user_metadata": { "prefs": { "theme": "light", "lang": "en" } }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/users/auth0%7C123456789"))
+        .and(bearer_token("test_token"))
+        .and(body_json(serde_json::json!({
+            "user_metadata": { "prefs": { "theme": "dark", "lang": "en" } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user_id": "auth0|123456789"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let patch = MetadataPatch::new().set_path(["prefs", "theme"], "dark");
+    client
+        .users()
+        .patch_metadata(UserId::new("auth0|123456789"), None, Some(patch))
+        .await
+        .expect("Failed to patch nested metadata");
+}
+
+#[tokio::test]
+async fn test_patch_metadata_empty_is_rejected() {
+    let (_server, client) = setup_mock_server().await;
+
+    let result = client
+        .users()
+        .patch_metadata(
+            UserId::new("auth0|123456789"),
+            Some(MetadataPatch::new()),
+            None,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(auth0_mgmt_api::Auth0Error::Configuration(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_update_user_clears_field_with_null() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/api/v2/users/auth0%7C123456789"))
+        .and(bearer_token("test_token"))
+        .and(body_json(serde_json::json!({
+            "nickname": "jd",
+            "picture": null
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user_id": "auth0|123456789",
+            "nickname": "jd",
+            "given_name": "Jane"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = UpdateUserRequest {
+        nickname: Patch::Value("jd".to_string()),
+        picture: Patch::Null,
+        ..Default::default()
+    };
+
+    let user = client
+        .users()
+        .update(UserId::new("auth0|123456789"), request)
+        .await
+        .expect("Failed to update user");
+
+    assert_eq!(user.given_name, Some("Jane".to_string()));
+}
+
+#[test]
+fn test_create_user_builder() {
+    let request = CreateUserRequest::builder()
+        .connection("Username-Password-Authentication")
+        .email("built@example.com")
+        .app_metadata(AppMeta {
+            plan: "pro".to_string(),
+            roles: vec![],
+        })
+        .build()
+        .expect("Failed to build request");
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({
+            "connection": "Username-Password-Authentication",
+            "email": "built@example.com",
+            "app_metadata": { "plan": "pro", "roles": [] }
+        })
+    );
+
+    let invalid_email = CreateUserRequest::builder()
+        .connection("Username-Password-Authentication")
+        .email("not-an-email")
+        .build();
+    assert!(invalid_email.is_err());
+}
+
+#[test]
+fn test_update_user_builder() {
+    let request = UpdateUserRequest::builder()
+        .nickname("jd")
+        .clear_picture()
+        .build()
+        .expect("Failed to build request");
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        serde_json::json!({ "nickname": "jd", "picture": null })
+    );
+
+    assert!(UpdateUserRequest::builder().build().is_err());
+}
+
+#[tokio::test]
+async fn test_link_identity() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/users/auth0%7C123/identities"))
+        .and(body_json(serde_json::json!({
+            "provider": "github",
+            "user_id": "98765",
+            "connection_id": "con_github"
+        })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!([
+            {
+                "connection": "Username-Password-Authentication",
+                "user_id": "123",
+                "provider": "auth0",
+                "isSocial": false
+            },
+            {
+                "connection": "github",
+                "user_id": 98765,
+                "provider": "github",
+                "isSocial": true,
+                "access_token": "gho_secret",
+                "profileData": {
+                    "email": "octo@example.com",
+                    "name": "Octo Cat",
+                    "public_repos": 8
+                }
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let request = LinkIdentityRequest::user("github", "98765").connection_id("con_github");
+    let identities = client
+        .users()
+        .link_identity(UserId::new("auth0|123"), request)
+        .await
+        .expect("Failed to link identity");
+
+    assert_eq!(identities.len(), 2);
+    assert!(!identities[0].has_access_token());
+    assert_eq!(
+        identities[0].strategy(),
+        Some(ConnectionStrategy::Auth0Database)
+    );
+
+    let github = &identities[1];
+    assert_eq!(github.user_id, "98765");
+    assert!(github.has_access_token());
+    assert_eq!(github.strategy(), Some(ConnectionStrategy::GitHub));
+    let profile = github.profile_data.as_ref().expect("Expected profileData");
+    assert_eq!(profile.email.as_deref(), Some("octo@example.com"));
+    assert_eq!(profile.extra["public_repos"], 8);
+    assert!(!format!("{:?}", github).contains("gho_secret"));
+}
+
+#[tokio::test]
+async fn test_link_identity_with_token() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/users/auth0%7C123/identities"))
+        .and(body_json(
+            serde_json::json!({ "link_with": "eyJ.secondary.token" }),
+        ))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!([])))
+        .mount(&server)
+        .await;
+
+    let request = LinkIdentityRequest::token("eyJ.secondary.token");
+    assert!(!format!("{:?}", request).contains("eyJ.secondary.token"));
+
+    let identities = client
+        .users()
+        .link_identity(UserId::new("auth0|123"), request)
+        .await
+        .expect("Failed to link identity with token");
+
+    assert!(identities.is_empty());
+}
+
+#[tokio::test]
+async fn test_unlink_identity() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/users/auth0%7C123/identities/google-oauth2/1234567890",
+        ))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "connection": "Username-Password-Authentication",
+                "user_id": "123",
+                "provider": "auth0",
+                "isSocial": false
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let identities = client
+        .users()
+        .unlink_identity(UserId::new("auth0|123"), "google-oauth2", "1234567890")
+        .await
+        .expect("Failed to unlink identity");
+
+    assert_eq!(identities.len(), 1);
+    assert_eq!(identities[0].provider, "auth0");
+}
+
+#[tokio::test]
+async fn test_list_enrollments() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/users/auth0%7C123/enrollments"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "id": "sms|dev_abc",
+                "status": "confirmed",
+                "type": "sms",
+                "phone_number": "+15555550100",
+                "auth_method": "sms",
+                "enrolled_at": "2023-11-15T10:30:00.000Z"
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let enrollments = client
+        .users()
+        .list_enrollments(UserId::new("auth0|123"))
+        .await
+        .expect("Failed to list enrollments");
+
+    assert_eq!(enrollments.len(), 1);
+    assert_eq!(enrollments[0].status, Some(EnrollmentStatus::Confirmed));
+    assert_eq!(enrollments[0].enrollment_type.as_deref(), Some("sms"));
+}
+
+#[tokio::test]
+async fn test_invalidate_remember_browser() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v2/users/auth0%7C123/multifactor/actions/invalidate-remember-browser",
+        ))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .users()
+        .invalidate_remember_browser(UserId::new("auth0|123"))
+        .await
+        .expect("Failed to invalidate remember browser");
+}
+
+#[tokio::test]
+async fn test_list_authentication_methods() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v2/users/auth0%7C123/authentication-methods"))
+        .and(query_param("per_page", "10"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "id": "phone|dev_1",
+                "type": "phone",
+                "confirmed": true,
+                "phone_number": "+15555550100",
+                "preferred_authentication_method": "voice"
+            },
+            {
+                "id": "webauthn-roaming|dev_2",
+                "type": "webauthn-roaming",
+                "key_id": "key_1",
+                "public_key": "pk",
+                "relying_party_identifier": "example.com"
+            },
+            { "id": "recovery-code|dev_3", "type": "recovery-code" },
+            {
+                "id": "webauthn-platform|dev_4",
+                "type": "webauthn-platform",
+                "name": "MacBook Touch ID"
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let params = PaginationParams {
+        per_page: Some(10),
+        ..Default::default()
+    };
+    let methods = client
+        .users()
+        .list_authentication_methods(UserId::new("auth0|123"), Some(params))
+        .await
+        .expect("Failed to list authentication methods");
+
+    assert_eq!(methods.len(), 4);
+    match &methods[0] {
+        AuthenticationMethod::Phone {
+            info,
+            phone_number,
+            preferred_authentication_method,
+        } => {
+            assert_eq!(info.confirmed, Some(true));
+            assert_eq!(phone_number.as_deref(), Some("+15555550100"));
+            assert_eq!(
+                *preferred_authentication_method,
+                Some(PhoneDeliveryMethod::Voice)
+            );
+        }
+        other => panic!("Expected phone method, got {:?}", other),
+    }
+    assert!(matches!(
+        &methods[1],
+        AuthenticationMethod::WebauthnRoaming { key_id: Some(k), .. } if k == "key_1"
+    ));
+    assert_eq!(methods[2].id(), "recovery-code|dev_3");
+    match &methods[3] {
+        AuthenticationMethod::Other { kind, extra, .. } => {
+            assert_eq!(kind, "webauthn-platform");
+            assert_eq!(extra["name"], "MacBook Touch ID");
+        }
+        other => panic!("Expected other method, got {:?}", other),
+    }
+    assert_eq!(methods[3].id(), "webauthn-platform|dev_4");
+}
+
+#[tokio::test]
+async fn test_create_authentication_method() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/users/auth0%7C123/authentication-methods"))
+        .and(body_json(serde_json::json!({
+            "type": "totp",
+            "totp_secret": "JBSWY3DPEHPK3PXP"
+        })))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "totp|dev_5",
+            "type": "totp",
+            "created_at": "2023-11-15T10:30:00.000Z"
+        })))
+        .mount(&server)
+        .await;
+
+    let request = CreateAuthenticationMethodRequest::Totp {
+        totp_secret: "JBSWY3DPEHPK3PXP".into(),
+        name: None,
+    };
+    let created = client
+        .users()
+        .create_authentication_method(UserId::new("auth0|123"), request)
+        .await
+        .expect("Failed to create authentication method");
+
+    assert!(matches!(created, AuthenticationMethod::Totp { .. }));
+    assert_eq!(created.id(), "totp|dev_5");
+}
+
+#[tokio::test]
+async fn test_delete_authentication_methods() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/api/v2/users/auth0%7C123/authentication-methods/phone%7Cdev_1",
+        ))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/api/v2/users/auth0%7C123/authentication-methods"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .users()
+        .delete_authentication_method(UserId::new("auth0|123"), "phone|dev_1")
+        .await
+        .expect("Failed to delete authentication method");
+    client
+        .users()
+        .delete_authentication_methods(UserId::new("auth0|123"))
+        .await
+        .expect("Failed to delete authentication methods");
+}
+
+#[tokio::test]
+async fn test_regenerate_recovery_code() {
+    let (server, client) = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v2/users/auth0%7C123/recovery-code-regeneration"))
+        .and(bearer_token("test_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "recovery_code": "ABCD1234EFGH5678"
+        })))
+        .mount(&server)
+        .await;
+
+    let code = client
+        .users()
+        .regenerate_recovery_code(UserId::new("auth0|123"))
+        .await
+        .expect("Failed to regenerate recovery code");
+
+    assert_eq!(code.recovery_code.expose_secret(), "ABCD1234EFGH5678");
+}